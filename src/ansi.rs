@@ -0,0 +1,173 @@
+use ratatui::prelude::*;
+
+pub fn strip(text: &str) -> String {
+    let mut plain = String::with_capacity(text.len());
+    let mut chars = text.chars().peekable();
+    while let Some(character) = chars.next() {
+        if character == '\u{1b}' {
+            skip_escape(&mut chars);
+        } else {
+            plain.push(character);
+        }
+    }
+    plain
+}
+
+pub fn to_line(text: &str) -> Line<'static> {
+    let mut spans = Vec::new();
+    let mut style = Style::default();
+    let mut current = String::new();
+    let mut chars = text.chars().peekable();
+
+    while let Some(character) = chars.next() {
+        if character != '\u{1b}' {
+            current.push(character);
+            continue;
+        }
+
+        if chars.peek() != Some(&'[') {
+            skip_escape(&mut chars);
+            continue;
+        }
+        chars.next();
+
+        let mut params = String::new();
+        let mut final_byte = None;
+        for next in chars.by_ref() {
+            if ('\u{40}'..='\u{7e}').contains(&next) {
+                final_byte = Some(next);
+                break;
+            }
+            params.push(next);
+        }
+
+        if final_byte == Some('m') {
+            if !current.is_empty() {
+                spans.push(Span::styled(std::mem::take(&mut current), style));
+            }
+            style = apply_sgr(style, &params);
+        }
+    }
+
+    if !current.is_empty() {
+        spans.push(Span::styled(current, style));
+    }
+    Line::from(spans)
+}
+
+fn skip_escape(chars: &mut std::iter::Peekable<std::str::Chars<'_>>) {
+    match chars.next() {
+        Some('[') => {
+            for next in chars.by_ref() {
+                if ('\u{40}'..='\u{7e}').contains(&next) {
+                    break;
+                }
+            }
+        }
+        Some(']') => {
+            while let Some(next) = chars.next() {
+                if next == '\u{7}' {
+                    break;
+                }
+                if next == '\u{1b}' && chars.peek() == Some(&'\\') {
+                    chars.next();
+                    break;
+                }
+            }
+        }
+        _ => {}
+    }
+}
+
+fn apply_sgr(mut style: Style, params: &str) -> Style {
+    let codes: Vec<u16> = if params.is_empty() {
+        vec![0]
+    } else {
+        params
+            .split([';', ':'])
+            .map(|code| code.parse().unwrap_or(0))
+            .collect()
+    };
+
+    let mut index = 0;
+    while index < codes.len() {
+        match codes[index] {
+            0 => style = Style::default(),
+            1 => style = style.add_modifier(Modifier::BOLD),
+            2 => style = style.add_modifier(Modifier::DIM),
+            3 => style = style.add_modifier(Modifier::ITALIC),
+            4 => style = style.add_modifier(Modifier::UNDERLINED),
+            7 => style = style.add_modifier(Modifier::REVERSED),
+            9 => style = style.add_modifier(Modifier::CROSSED_OUT),
+            22 => style = style.remove_modifier(Modifier::BOLD | Modifier::DIM),
+            23 => style = style.remove_modifier(Modifier::ITALIC),
+            24 => style = style.remove_modifier(Modifier::UNDERLINED),
+            27 => style = style.remove_modifier(Modifier::REVERSED),
+            29 => style = style.remove_modifier(Modifier::CROSSED_OUT),
+            code @ 30..=37 => style = style.fg(Color::Indexed((code - 30) as u8)),
+            code @ 90..=97 => style = style.fg(Color::Indexed((code - 90 + 8) as u8)),
+            39 => style.fg = None,
+            code @ 40..=47 => style = style.bg(Color::Indexed((code - 40) as u8)),
+            code @ 100..=107 => style = style.bg(Color::Indexed((code - 100 + 8) as u8)),
+            49 => style.bg = None,
+            code @ (38 | 48) => {
+                let (color, used) = extended_color(&codes[index + 1..]);
+                if let Some(color) = color {
+                    style = if code == 38 {
+                        style.fg(color)
+                    } else {
+                        style.bg(color)
+                    };
+                }
+                index += used;
+            }
+            _ => {}
+        }
+        index += 1;
+    }
+    style
+}
+
+fn extended_color(codes: &[u16]) -> (Option<Color>, usize) {
+    match codes {
+        [5, index, ..] => (Some(Color::Indexed(*index as u8)), 2),
+        [2, red, green, blue, ..] => (Some(Color::Rgb(*red as u8, *green as u8, *blue as u8)), 4),
+        _ => (None, codes.len()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn strips_sgr_and_other_escapes() {
+        assert_eq!(
+            strip("\u{1b}[1;31merror\u{1b}[0m: \u{1b}]8;;http://x\u{7}boom"),
+            "error: boom"
+        );
+    }
+
+    #[test]
+    fn parses_sgr_into_styled_spans() {
+        let line =
+            to_line("ok \u{1b}[1;31mfail\u{1b}[39;48;5;236m dim\u{1b}[38;2;1;2;3mrgb\u{1b}[m.");
+
+        let spans: Vec<(&str, Style)> = line
+            .spans
+            .iter()
+            .map(|span| (span.content.as_ref(), span.style))
+            .collect();
+        let bold = Style::default().add_modifier(Modifier::BOLD);
+        assert_eq!(
+            spans,
+            vec![
+                ("ok ", Style::default()),
+                ("fail", bold.fg(Color::Indexed(1))),
+                (" dim", bold.bg(Color::Indexed(236))),
+                ("rgb", bold.fg(Color::Rgb(1, 2, 3)).bg(Color::Indexed(236))),
+                (".", Style::default()),
+            ]
+        );
+    }
+}
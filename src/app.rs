@@ -1,6 +1,10 @@
-use crate::models::{Pane, Session, Window};
+use crate::ansi;
+use crate::config::{self, Config, LayoutPreset, SplitDirection};
+use crate::models::{Buffer, Client, Pane, PaneLocation, Session, Window};
 use crate::tmux::{self, TmuxConnectionState};
-use ratatui::widgets::ListState;
+use crate::util;
+use ratatui::{layout::Rect, widgets::ListState};
+use std::{cmp::Reverse, collections::HashMap};
 
 #[derive(PartialEq, Clone, Copy, Debug, Eq)]
 pub enum FocusArea {
@@ -96,19 +100,200 @@ impl FilterState {
     }
 }
 
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MarkedPane {
+    pub pane_id: String,
+    pub window_id: String,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PendingRefresh {
+    WindowsAndPanes,
+    Panes,
+    Preview,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum SessionStateFilter {
+    #[default]
+    All,
+    Attached,
+    Detached,
+}
+
+impl SessionStateFilter {
+    pub fn next(self) -> Self {
+        match self {
+            Self::All => Self::Attached,
+            Self::Attached => Self::Detached,
+            Self::Detached => Self::All,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::All => "all",
+            Self::Attached => "attached",
+            Self::Detached => "detached",
+        }
+    }
+
+    fn matches(self, session: &Session) -> bool {
+        match self {
+            Self::All => true,
+            Self::Attached => session.attached > 0,
+            Self::Detached => session.attached == 0,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum SessionSort {
+    #[default]
+    Name,
+    Created,
+    Activity,
+}
+
+impl SessionSort {
+    pub fn next(self) -> Self {
+        match self {
+            Self::Name => Self::Created,
+            Self::Created => Self::Activity,
+            Self::Activity => Self::Name,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Name => "name",
+            Self::Created => "creation time",
+            Self::Activity => "recent activity",
+        }
+    }
+
+    fn sort(self, sessions: &mut [Session]) {
+        match self {
+            Self::Name => sessions.sort_by(|a, b| a.name.cmp(&b.name)),
+            Self::Created => sessions.sort_by_key(|session| session.created_at),
+            Self::Activity => sessions.sort_by_key(|session| Reverse(session.last_activity)),
+        }
+    }
+}
+
+pub const PREVIEW_LINE_LIMIT: usize = 200;
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct PanePreview {
+    pub pane_id: Option<String>,
+    pub lines: Vec<String>,
+    pub error: Option<String>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CommandSearch {
+    pub query: String,
+    pub position: usize,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PendingPipe {
+    pub pane_id: String,
+    pub command: String,
+    pub content: String,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct WatchedPane {
+    pub pane_id: String,
+    pub command: String,
+}
+
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum InputIntent {
     NewSession,
     RenameSession,
     NewWindow,
     RenameWindow,
+    AttachCommand,
+    SaveLayout,
+    DuplicateWindow,
+    DevSession,
+    SessionHook(&'static str),
+    MoveWindow,
+    FindCommand,
+    SendKeys,
+    PipeCommand,
+    WindowsFromDirectory,
+    SessionDirectory,
+    GroupedSession,
+    PaneTitle,
+    KillServer,
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct InputModalState {
     pub intent: InputIntent,
     pub value: String,
+    pub cursor: usize,
     pub error: Option<String>,
+    pub warning: Option<String>,
+}
+
+impl InputModalState {
+    pub fn clear_feedback(&mut self) {
+        self.error = None;
+        self.warning = None;
+    }
+
+    pub fn insert(&mut self, character: char) {
+        let at = self.byte_index();
+        self.value.insert(at, character);
+        self.cursor += 1;
+    }
+
+    pub fn backspace(&mut self) {
+        if self.cursor > 0 {
+            self.cursor -= 1;
+            let at = self.byte_index();
+            self.value.remove(at);
+        }
+    }
+
+    pub fn delete(&mut self) {
+        if self.cursor < self.value.chars().count() {
+            let at = self.byte_index();
+            self.value.remove(at);
+        }
+    }
+
+    pub fn clear(&mut self) {
+        self.value.clear();
+        self.cursor = 0;
+    }
+
+    pub fn move_left(&mut self) {
+        self.cursor = self.cursor.saturating_sub(1);
+    }
+
+    pub fn move_right(&mut self) {
+        self.cursor = (self.cursor + 1).min(self.value.chars().count());
+    }
+
+    pub fn move_home(&mut self) {
+        self.cursor = 0;
+    }
+
+    pub fn move_end(&mut self) {
+        self.cursor = self.value.chars().count();
+    }
+
+    fn byte_index(&self) -> usize {
+        self.value
+            .char_indices()
+            .nth(self.cursor)
+            .map_or(self.value.len(), |(index, _)| index)
+    }
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
@@ -116,19 +301,356 @@ pub enum ConfirmIntent {
     Session,
     Window,
     Pane,
+    Attach,
+    KillServer,
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct ConfirmModalState {
     pub intent: ConfirmIntent,
+    pub windows: Vec<String>,
+    pub scroll: usize,
+    pub error: Option<String>,
+}
+
+impl ConfirmModalState {
+    pub fn scroll_down(&mut self) {
+        self.scroll = (self.scroll + 1).min(self.windows.len().saturating_sub(1));
+    }
+
+    pub fn scroll_up(&mut self) {
+        self.scroll = self.scroll.saturating_sub(1);
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PickerIntent {
+    PanePosition,
+    RecentlyKilled,
+    Buffers,
+    LayoutPresets,
+    Actions,
+    Sockets,
+    Clients,
+    Hooks,
+    Layouts,
+    MergeSources,
+    JoinSources,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PickerItem {
+    pub label: String,
+    pub detail: String,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct MenuAction {
+    pub key: char,
+    pub label: &'static str,
+}
+
+impl MenuAction {
+    const fn new(key: char, label: &'static str) -> Self {
+        Self { key, label }
+    }
+
+    pub fn key_label(&self) -> String {
+        match self.key {
+            '\n' => "Enter".to_string(),
+            ' ' => "Space".to_string(),
+            '\u{8}' => "Backspace".to_string(),
+            key @ '\u{1}'..='\u{1a}' => format!("Ctrl+{}", (key as u8 + b'A' - 1) as char),
+            key => key.to_string(),
+        }
+    }
+}
+
+const SESSION_ACTIONS: &[MenuAction] = &[
+    MenuAction::new('\n', "Attach to the session"),
+    MenuAction::new('a', "Attach and run a command"),
+    MenuAction::new('n', "New session"),
+    MenuAction::new('N', "New session with a 3-pane dev layout"),
+    MenuAction::new('C', "New session grouped with this one"),
+    MenuAction::new('X', "List and detach clients"),
+    MenuAction::new('R', "Rename session"),
+    MenuAction::new('d', "Delete session"),
+    MenuAction::new('D', "Toggle destroy-unattached"),
+    MenuAction::new('e', "Run a command on a session hook"),
+    MenuAction::new('M', "Merge another session into this one"),
+    MenuAction::new('W', "Create a window per subdirectory"),
+    MenuAction::new('Y', "Copy the session tree as text"),
+    MenuAction::new('U', "Recreate a deleted session"),
+    MenuAction::new('o', "Sort by name, creation time, or activity"),
+];
+
+pub const SESSION_HOOKS: &[(&str, &str)] = &[
+    ("after-new-window", "a window is created"),
+    ("after-split-window", "a pane is split"),
+    ("after-kill-pane", "a pane is deleted"),
+    ("after-rename-window", "a window is renamed"),
+    ("client-attached", "a client attaches"),
+    ("client-detached", "a client detaches"),
+    ("pane-exited", "a pane's program exits"),
+    ("session-renamed", "the session is renamed"),
+];
+
+const WINDOW_ACTIONS: &[MenuAction] = &[
+    MenuAction::new('\n', "Attach to the window"),
+    MenuAction::new('a', "Attach and run a command"),
+    MenuAction::new('n', "New window"),
+    MenuAction::new('R', "Rename window"),
+    MenuAction::new('b', "Rename window to its git branch"),
+    MenuAction::new('d', "Delete window"),
+    MenuAction::new('\u{8}', "Switch to the last window"),
+    MenuAction::new('C', "Clone window"),
+    MenuAction::new('D', "Duplicate window into a new session"),
+    MenuAction::new('M', "Move window to another session"),
+    MenuAction::new('K', "Move window up"),
+    MenuAction::new('J', "Move window down"),
+    MenuAction::new('A', "Toggle aggressive-resize"),
+    MenuAction::new('y', "Toggle synchronize-panes"),
+    MenuAction::new('t', "Tile panes or restore the layout"),
+    MenuAction::new('=', "Make all panes the same size"),
+    MenuAction::new('w', "Save the layout as a preset"),
+    MenuAction::new('L', "Apply a saved layout"),
+    MenuAction::new('V', "Apply a built-in layout"),
+    MenuAction::new(' ', "Cycle through the built-in layouts"),
+    MenuAction::new('\u{f}', "Rotate the panes"),
+    MenuAction::new(']', "Switch to the next window"),
+    MenuAction::new('[', "Switch to the previous window"),
+    MenuAction::new('!', "Open a shell in the window's directory"),
+];
+
+const PANE_ACTIONS: &[MenuAction] = &[
+    MenuAction::new('\n', "Attach to the pane"),
+    MenuAction::new('a', "Attach and run a command"),
+    MenuAction::new('s', "Send a command without attaching"),
+    MenuAction::new('n', "Split pane"),
+    MenuAction::new('d', "Delete pane"),
+    MenuAction::new('o', "Make the next pane active"),
+    MenuAction::new('P', "Move pane to a position"),
+    MenuAction::new('\u{f}', "Rotate the panes in the window"),
+    MenuAction::new('R', "Set the pane title"),
+    MenuAction::new('M', "Make main pane"),
+    MenuAction::new('b', "Break pane out into its own window"),
+    MenuAction::new('J', "Join a pane from another window"),
+    MenuAction::new('z', "Zoom or unzoom pane"),
+    MenuAction::new('<', "Move the pane's border left"),
+    MenuAction::new('>', "Move the pane's border right"),
+    MenuAction::new('-', "Move the pane's border up"),
+    MenuAction::new('+', "Move the pane's border down"),
+    MenuAction::new(' ', "Mark or unmark pane"),
+    MenuAction::new('x', "Swap marked panes, or one with the selection"),
+    MenuAction::new('e', "Copy the pane's command"),
+    MenuAction::new('c', "Copy the pane's directory"),
+    MenuAction::new('w', "Watch for the command to finish"),
+    MenuAction::new('v', "View scrollback"),
+    MenuAction::new('Y', "Copy scrollback to a buffer"),
+    MenuAction::new('|', "Pipe scrollback to a command"),
+    MenuAction::new('B', "Paste a buffer"),
+    MenuAction::new('!', "Open a shell in the pane's directory"),
+];
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PickerModalState {
+    pub intent: PickerIntent,
+    pub items: Vec<PickerItem>,
+    pub list_state: ListState,
+    pub error: Option<String>,
+}
+
+impl PickerModalState {
+    pub fn selected_index(&self) -> Option<usize> {
+        self.list_state
+            .selected()
+            .filter(|index| *index < self.items.len())
+    }
+}
+
+const SPLIT_PERCENT_MIN: u16 = 10;
+const SPLIT_PERCENT_MAX: u16 = 90;
+const SPLIT_PERCENT_STEP: u16 = 5;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SplitModalState {
+    pub pane_id: String,
+    pub width: u16,
+    pub height: u16,
+    pub direction: Option<SplitDirection>,
+    pub percent: u16,
     pub error: Option<String>,
 }
 
+impl SplitModalState {
+    pub fn grow(&mut self) {
+        self.percent = (self.percent + SPLIT_PERCENT_STEP).min(SPLIT_PERCENT_MAX);
+        self.error = None;
+    }
+
+    pub fn shrink(&mut self) {
+        self.percent = self
+            .percent
+            .saturating_sub(SPLIT_PERCENT_STEP)
+            .max(SPLIT_PERCENT_MIN);
+        self.error = None;
+    }
+
+    pub fn choose(&mut self, direction: SplitDirection) {
+        self.direction = Some(direction);
+        self.error = None;
+    }
+
+    pub fn sizes(&self) -> (u16, u16) {
+        match self.direction {
+            Some(SplitDirection::Horizontal) => split_sizes(self.width, self.percent),
+            _ => split_sizes(self.height, self.percent),
+        }
+    }
+}
+
+pub fn split_sizes(total: u16, percent: u16) -> (u16, u16) {
+    let new = (u32::from(total) * u32::from(percent) / 100) as u16;
+    (total.saturating_sub(new + 1), new)
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PagerState {
+    pub title: String,
+    pub lines: Vec<String>,
+    pub visible: Vec<usize>,
+    pub scroll: usize,
+    pub viewport_height: usize,
+    pub query: String,
+    pub searching: bool,
+    pub search_error: Option<String>,
+    pub pane_id: Option<String>,
+    pub preview_focused: bool,
+    pub ansi: bool,
+    pub ansi_lines: Option<Vec<String>>,
+}
+
+impl PagerState {
+    pub fn new(title: impl Into<String>, content: &str) -> Self {
+        let lines: Vec<String> = content.lines().map(str::to_string).collect();
+        let visible = (0..lines.len()).collect();
+        Self {
+            title: title.into(),
+            lines,
+            visible,
+            scroll: usize::MAX,
+            viewport_height: 0,
+            query: String::new(),
+            searching: false,
+            search_error: None,
+            pane_id: None,
+            preview_focused: true,
+            ansi: false,
+            ansi_lines: None,
+        }
+    }
+
+    pub fn show_pane(&mut self, pane_id: impl Into<String>, content: &str) {
+        let pane_id = pane_id.into();
+        self.title = format!("Scrollback {pane_id}");
+        self.pane_id = Some(pane_id);
+        if self.ansi {
+            let raw: Vec<String> = content.lines().map(str::to_string).collect();
+            self.lines = raw.iter().map(|line| ansi::strip(line)).collect();
+            self.ansi_lines = Some(raw);
+        } else {
+            self.lines = content.lines().map(str::to_string).collect();
+            self.ansi_lines = None;
+        }
+        self.apply_query();
+    }
+
+    pub fn toggle_focus(&mut self) {
+        self.preview_focused = !self.preview_focused || self.pane_id.is_none();
+    }
+
+    pub fn max_scroll(&self) -> usize {
+        self.visible.len().saturating_sub(self.viewport_height)
+    }
+
+    pub fn set_viewport_height(&mut self, height: usize) {
+        self.viewport_height = height;
+        self.scroll = self.scroll.min(self.max_scroll());
+    }
+
+    pub fn scroll_down(&mut self, amount: usize) {
+        self.scroll = self.scroll.saturating_add(amount).min(self.max_scroll());
+    }
+
+    pub fn scroll_up(&mut self, amount: usize) {
+        self.scroll = self.scroll.min(self.max_scroll()).saturating_sub(amount);
+    }
+
+    pub fn scroll_to_top(&mut self) {
+        self.scroll = 0;
+    }
+
+    pub fn scroll_to_bottom(&mut self) {
+        self.scroll = self.max_scroll();
+    }
+
+    pub fn page_size(&self) -> usize {
+        self.viewport_height.max(2) / 2
+    }
+
+    pub fn start_search(&mut self) {
+        self.searching = true;
+    }
+
+    pub fn finish_search(&mut self) {
+        self.searching = false;
+    }
+
+    pub fn push_query_char(&mut self, character: char) {
+        self.query.push(character);
+        self.apply_query();
+    }
+
+    pub fn pop_query_char(&mut self) {
+        self.query.pop();
+        self.apply_query();
+    }
+
+    pub fn clear_query(&mut self) {
+        self.query.clear();
+        self.searching = false;
+        self.apply_query();
+    }
+
+    pub fn visible_line_indices(&self) -> impl Iterator<Item = usize> + '_ {
+        self.visible
+            .iter()
+            .skip(self.scroll.min(self.max_scroll()))
+            .take(self.viewport_height)
+            .copied()
+    }
+
+    pub fn visible_lines(&self) -> impl Iterator<Item = &str> {
+        self.visible_line_indices()
+            .map(|index| self.lines[index].as_str())
+    }
+
+    fn apply_query(&mut self) {
+        let (visible, error) = matching_lines(&self.lines, self.query.trim());
+        self.visible = visible;
+        self.search_error = error;
+        self.scroll_to_bottom();
+    }
+}
+
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub enum ModalState {
     None,
     Input(InputModalState),
     Confirm(ConfirmModalState),
+    Picker(PickerModalState),
+    Pager(PagerState),
+    Split(SplitModalState),
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -147,6 +669,14 @@ pub struct ActionAvailability {
     pub delete: ActionItem,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct KilledSession {
+    pub name: String,
+    pub path: String,
+}
+
+const RECENTLY_KILLED_LIMIT: usize = 10;
+
 pub struct App {
     pub sessions: Vec<Session>,
     pub windows: Vec<Window>,
@@ -160,9 +690,42 @@ pub struct App {
     pub filter: FilterState,
     pub should_quit: bool,
     pub target_attach: Option<String>,
+    pub target_shell: Option<String>,
+    pub attach_command: Option<String>,
+    pub detach_and_quit: bool,
+    pub mouse: Option<bool>,
+    pub resized_to: Option<(u16, u16)>,
+    pub compact_inactive_panes: bool,
+    pub list_rows: usize,
+    pub panel_areas: Vec<(FocusArea, Rect)>,
+    pub show_ids: bool,
+    pub session_state_filter: SessionStateFilter,
+    pub session_sort: SessionSort,
+    pub recently_killed: Vec<KilledSession>,
+    pub layout_backups: HashMap<String, String>,
+    pub layout_cycle: HashMap<String, usize>,
+    pub socket: Option<String>,
+    pub sockets: Vec<String>,
+    pub clients: Vec<Client>,
+    pub join_sources: Vec<PaneLocation>,
+    pub layout_presets: Vec<LayoutPreset>,
+    pub marked_panes: Vec<MarkedPane>,
+    pub watched_panes: Vec<WatchedPane>,
+    pub command_search: Option<CommandSearch>,
+    pub preview: Option<PanePreview>,
+    pub pending_pipe: Option<PendingPipe>,
+    pub pending_session: Option<String>,
+    pub buffers: Vec<Buffer>,
+    pub config: Config,
+    pub header_title: String,
+    pub hostname: Option<String>,
+    pub own_session_id: Option<String>,
+    pub tmux_binary: String,
     pub banner: BannerState,
     pub connection: TmuxConnectionState,
     pub connection_detail: Option<String>,
+    pub needs_redraw: bool,
+    pub pending_refresh: Option<PendingRefresh>,
 }
 
 impl Default for App {
@@ -180,9 +743,42 @@ impl Default for App {
             filter: FilterState::default(),
             should_quit: false,
             target_attach: None,
+            target_shell: None,
+            attach_command: None,
+            detach_and_quit: false,
+            mouse: None,
+            resized_to: None,
+            compact_inactive_panes: false,
+            list_rows: 0,
+            panel_areas: Vec::new(),
+            show_ids: false,
+            session_state_filter: SessionStateFilter::All,
+            session_sort: SessionSort::default(),
+            recently_killed: Vec::new(),
+            layout_backups: HashMap::new(),
+            layout_cycle: HashMap::new(),
+            socket: None,
+            sockets: Vec::new(),
+            clients: Vec::new(),
+            join_sources: Vec::new(),
+            layout_presets: Vec::new(),
+            marked_panes: Vec::new(),
+            watched_panes: Vec::new(),
+            command_search: None,
+            preview: None,
+            pending_pipe: None,
+            pending_session: None,
+            buffers: Vec::new(),
+            config: Config::default(),
+            header_title: "TMUXUI".to_string(),
+            hostname: None,
+            own_session_id: None,
+            tmux_binary: "tmux".to_string(),
             banner: BannerState::info("Loading tmux", "Checking the current tmux server state."),
             connection: TmuxConnectionState::Connected,
             connection_detail: None,
+            needs_redraw: true,
+            pending_refresh: None,
         }
     }
 }
@@ -190,7 +786,22 @@ impl Default for App {
 impl App {
     pub fn new() -> Self {
         let mut app = Self::default();
+        let config_error = match config::load() {
+            Ok(config) => {
+                app.config = config;
+                None
+            }
+            Err(err) => Some(err),
+        };
+        app.hostname = util::hostname();
+        app.header_title =
+            util::header_title(app.config.header_title.as_deref(), app.hostname.as_deref());
+        app.tmux_binary = tmux::binary();
+        app.own_session_id = tmux::own_session_id();
         app.refresh_all();
+        if let Some(err) = config_error {
+            app.set_warning_banner("Config not loaded", format!("Using defaults. {err:#}"));
+        }
         app
     }
 
@@ -201,19 +812,32 @@ impl App {
         let selected_window_id = self.get_selected_window().map(|window| window.id.clone());
         let selected_pane_id = self.get_selected_pane().map(|pane| pane.id.clone());
 
+        self.needs_redraw = true;
+        self.pending_refresh = None;
         let snapshot = tmux::get_sessions_snapshot();
         self.sessions = snapshot.sessions;
+        self.session_sort.sort(&mut self.sessions);
         self.connection = snapshot.connection;
         self.connection_detail = snapshot.detail;
         self.sync_session_selection(selected_session_id.as_deref());
+        let (selected_window_id, selected_pane_id) =
+            if self.get_selected_session().map(|session| &session.id)
+                == selected_session_id.as_ref()
+            {
+                (selected_window_id, selected_pane_id)
+            } else {
+                (None, None)
+            };
 
         if self.connection != TmuxConnectionState::Connected {
+            self.mouse = None;
             self.clear_windows();
             self.clear_panes();
             self.reset_banner_for_current_state();
             return;
         }
 
+        self.mouse = tmux::mouse_enabled().ok();
         self.refresh_windows_and_panes(selected_window_id.as_deref(), selected_pane_id.as_deref());
 
         if self.connection == TmuxConnectionState::Connected {
@@ -221,6 +845,15 @@ impl App {
         }
     }
 
+    pub fn refresh_quietly(&mut self) {
+        let connection = self.connection;
+        let banner = self.banner.clone();
+        self.refresh_all();
+        if self.connection == connection {
+            self.banner = banner;
+        }
+    }
+
     pub fn get_selected_session(&self) -> Option<&Session> {
         self.actual_session_index()
             .and_then(|index| self.sessions.get(index))
@@ -231,6 +864,16 @@ impl App {
             .and_then(|index| self.windows.get(index))
     }
 
+    pub fn window_neighbor(&self, down: bool) -> Option<&Window> {
+        let index = self.actual_window_index()?;
+        let neighbor = if down {
+            index + 1
+        } else {
+            index.checked_sub(1)?
+        };
+        self.windows.get(neighbor)
+    }
+
     pub fn get_selected_pane(&self) -> Option<&Pane> {
         self.actual_pane_index()
             .and_then(|index| self.panes.get(index))
@@ -250,21 +893,32 @@ impl App {
         self.get_selected_pane().map(|pane| pane.id.as_str())
     }
 
+    pub fn selected_path(&self) -> Option<&str> {
+        self.get_selected_pane()
+            .map(|pane| pane.current_path.as_str())
+            .or_else(|| {
+                self.get_selected_window()
+                    .map(|window| window.path.as_str())
+            })
+            .filter(|path| !path.is_empty())
+    }
+
     pub fn nav_down(&mut self) {
         match self.focus {
             FocusArea::Sessions => {
                 let len = self.visible_session_indices().len();
                 next_item(&mut self.session_list_state, len);
-                self.refresh_windows_and_panes(None, None);
+                self.queue_refresh(PendingRefresh::WindowsAndPanes);
             }
             FocusArea::Windows => {
                 let len = self.visible_window_indices().len();
                 next_item(&mut self.window_list_state, len);
-                self.refresh_panes_only();
+                self.queue_refresh(PendingRefresh::Panes);
             }
             FocusArea::Panes => {
                 let len = self.visible_pane_indices().len();
                 next_item(&mut self.pane_list_state, len);
+                self.queue_preview_refresh();
             }
         }
     }
@@ -274,16 +928,17 @@ impl App {
             FocusArea::Sessions => {
                 let len = self.visible_session_indices().len();
                 prev_item(&mut self.session_list_state, len);
-                self.refresh_windows_and_panes(None, None);
+                self.queue_refresh(PendingRefresh::WindowsAndPanes);
             }
             FocusArea::Windows => {
                 let len = self.visible_window_indices().len();
                 prev_item(&mut self.window_list_state, len);
-                self.refresh_panes_only();
+                self.queue_refresh(PendingRefresh::Panes);
             }
             FocusArea::Panes => {
                 let len = self.visible_pane_indices().len();
                 prev_item(&mut self.pane_list_state, len);
+                self.queue_preview_refresh();
             }
         }
     }
@@ -293,16 +948,17 @@ impl App {
             FocusArea::Sessions => {
                 let len = self.visible_session_indices().len();
                 select_first(&mut self.session_list_state, len);
-                self.refresh_windows_and_panes(None, None);
+                self.queue_refresh(PendingRefresh::WindowsAndPanes);
             }
             FocusArea::Windows => {
                 let len = self.visible_window_indices().len();
                 select_first(&mut self.window_list_state, len);
-                self.refresh_panes_only();
+                self.queue_refresh(PendingRefresh::Panes);
             }
             FocusArea::Panes => {
                 let len = self.visible_pane_indices().len();
                 select_first(&mut self.pane_list_state, len);
+                self.queue_preview_refresh();
             }
         }
     }
@@ -312,77 +968,537 @@ impl App {
             FocusArea::Sessions => {
                 let len = self.visible_session_indices().len();
                 select_last(&mut self.session_list_state, len);
-                self.refresh_windows_and_panes(None, None);
+                self.queue_refresh(PendingRefresh::WindowsAndPanes);
             }
             FocusArea::Windows => {
                 let len = self.visible_window_indices().len();
                 select_last(&mut self.window_list_state, len);
-                self.refresh_panes_only();
+                self.queue_refresh(PendingRefresh::Panes);
             }
             FocusArea::Panes => {
                 let len = self.visible_pane_indices().len();
                 select_last(&mut self.pane_list_state, len);
+                self.queue_preview_refresh();
             }
         }
     }
 
-    pub fn cycle_focus(&mut self) {
-        self.focus = match self.focus {
-            FocusArea::Sessions => FocusArea::Windows,
-            FocusArea::Windows => FocusArea::Panes,
-            FocusArea::Panes => FocusArea::Sessions,
+    pub fn nav_half_page(&mut self, down: bool) {
+        let item_rows = if self.focus == FocusArea::Panes && !self.compact_inactive_panes {
+            2
+        } else {
+            1
         };
+        let step = (self.list_rows / item_rows / 2).max(1);
+        match self.focus {
+            FocusArea::Sessions => {
+                let len = self.visible_session_indices().len();
+                move_selection(&mut self.session_list_state, len, step, down);
+                self.queue_refresh(PendingRefresh::WindowsAndPanes);
+            }
+            FocusArea::Windows => {
+                let len = self.visible_window_indices().len();
+                move_selection(&mut self.window_list_state, len, step, down);
+                self.queue_refresh(PendingRefresh::Panes);
+            }
+            FocusArea::Panes => {
+                let len = self.visible_pane_indices().len();
+                move_selection(&mut self.pane_list_state, len, step, down);
+                self.queue_preview_refresh();
+            }
+        }
     }
 
-    pub fn cycle_focus_back(&mut self) {
-        self.focus = match self.focus {
-            FocusArea::Sessions => FocusArea::Panes,
-            FocusArea::Windows => FocusArea::Sessions,
-            FocusArea::Panes => FocusArea::Windows,
+    pub fn scroll_list(&mut self, column: u16, row: u16, down: bool) -> bool {
+        let Some(hovered) = self
+            .panel_areas
+            .iter()
+            .find(|(_, area)| contains(*area, column, row))
+            .map(|(focus, _)| *focus)
+        else {
+            return false;
         };
-    }
 
-    pub fn set_info_banner(&mut self, title: impl Into<String>, body: impl Into<String>) {
-        self.banner = BannerState::info(
-            truncate_text(&title.into(), 60),
-            truncate_text(&body.into(), 180),
-        );
+        let focus = std::mem::replace(&mut self.focus, hovered);
+        if down {
+            self.nav_down();
+        } else {
+            self.nav_up();
+        }
+        self.focus = focus;
+        true
     }
 
-    pub fn set_success_banner(&mut self, title: impl Into<String>, body: impl Into<String>) {
-        self.banner = BannerState::success(
-            truncate_text(&title.into(), 60),
-            truncate_text(&body.into(), 180),
-        );
+    pub fn click_list(&mut self, column: u16, row: u16) -> Option<(FocusArea, usize)> {
+        let (focus, area) = self
+            .panel_areas
+            .iter()
+            .copied()
+            .find(|(_, area)| contains(*area, column, row))?;
+        self.focus = focus;
+
+        let inner_top = area.y + 1;
+        if row < inner_top || row + 1 >= area.y + area.height {
+            return None;
+        }
+        let (visible, offset) = match focus {
+            FocusArea::Sessions => (
+                self.visible_session_indices(),
+                self.session_list_state.offset(),
+            ),
+            FocusArea::Windows => (
+                self.visible_window_indices(),
+                self.window_list_state.offset(),
+            ),
+            FocusArea::Panes => (self.visible_pane_indices(), self.pane_list_state.offset()),
+        };
+
+        let mut top = inner_top;
+        for (position, actual) in visible.iter().enumerate().skip(offset) {
+            let height = match focus {
+                FocusArea::Panes if !self.compact_inactive_panes || self.panes[*actual].active => 2,
+                _ => 1,
+            };
+            if row < top + height {
+                match focus {
+                    FocusArea::Sessions => {
+                        self.session_list_state.select(Some(position));
+                        self.queue_refresh(PendingRefresh::WindowsAndPanes);
+                    }
+                    FocusArea::Windows => {
+                        self.window_list_state.select(Some(position));
+                        self.queue_refresh(PendingRefresh::Panes);
+                    }
+                    FocusArea::Panes => {
+                        self.pane_list_state.select(Some(position));
+                        self.queue_preview_refresh();
+                    }
+                }
+                return Some((focus, position));
+            }
+            top += height;
+        }
+        None
     }
 
-    pub fn set_warning_banner(&mut self, title: impl Into<String>, body: impl Into<String>) {
-        self.banner = BannerState::warning(
-            truncate_text(&title.into(), 60),
-            truncate_text(&body.into(), 180),
-        );
+    pub fn has_pending_refresh(&self) -> bool {
+        self.pending_refresh.is_some()
     }
 
-    pub fn set_error_banner(&mut self, title: impl Into<String>, body: impl Into<String>) {
-        self.banner = BannerState::error(
-            truncate_text(&title.into(), 60),
-            truncate_text(&body.into(), 180),
-        );
+    pub fn flush_pending_refresh(&mut self) {
+        match self.pending_refresh.take() {
+            Some(PendingRefresh::WindowsAndPanes) => self.refresh_windows_and_panes(None, None),
+            Some(PendingRefresh::Panes) => self.refresh_panes_only(),
+            Some(PendingRefresh::Preview) => self.refresh_preview(),
+            None => {}
+        }
     }
 
-    pub fn open_filter(&mut self) {
-        let focus = self.focus;
-        self.mutate_filter_preserving_selection(|filter| {
-            if filter.target != focus {
-                filter.query.clear();
-            }
-            filter.target = focus;
-            filter.active = true;
-        });
+    fn queue_refresh(&mut self, refresh: PendingRefresh) {
+        let replace = match self.pending_refresh {
+            None | Some(PendingRefresh::Preview) => true,
+            Some(PendingRefresh::Panes) => refresh == PendingRefresh::WindowsAndPanes,
+            Some(PendingRefresh::WindowsAndPanes) => false,
+        };
+        if replace {
+            self.pending_refresh = Some(refresh);
+        }
     }
 
-    pub fn close_filter(&mut self) {
-        self.filter.active = false;
+    fn queue_preview_refresh(&mut self) {
+        if self.preview.is_some() {
+            self.queue_refresh(PendingRefresh::Preview);
+        }
+    }
+
+    pub fn toggle_preview(&mut self) -> bool {
+        if self.preview.take().is_some() {
+            return false;
+        }
+        self.preview = Some(PanePreview::default());
+        self.refresh_preview();
+        true
+    }
+
+    pub fn refresh_preview(&mut self) {
+        let pane_id = self.get_selected_pane().map(|pane| pane.id.clone());
+        let Some(preview) = self.preview.as_mut() else {
+            return;
+        };
+
+        *preview = match pane_id {
+            Some(pane_id) => match tmux::capture_pane(&pane_id) {
+                Ok(raw) => PanePreview {
+                    lines: preview_lines(&raw, PREVIEW_LINE_LIMIT),
+                    pane_id: Some(pane_id),
+                    error: None,
+                },
+                Err(err) => PanePreview {
+                    pane_id: Some(pane_id),
+                    lines: Vec::new(),
+                    error: Some(format!("{err:#}")),
+                },
+            },
+            None => PanePreview::default(),
+        };
+        self.needs_redraw = true;
+    }
+
+    pub fn note_terminal_resize(&mut self, width: u16, height: u16) {
+        self.resized_to = Some((width, height));
+        self.set_info_banner(
+            "Terminal resized",
+            format!("The next attach fits the tmux window to {width}x{height}."),
+        );
+    }
+
+    pub fn remember_killed_session(&mut self, name: impl Into<String>, path: impl Into<String>) {
+        let name = name.into();
+        self.recently_killed.retain(|killed| killed.name != name);
+        self.recently_killed.insert(
+            0,
+            KilledSession {
+                name,
+                path: path.into(),
+            },
+        );
+        self.recently_killed.truncate(RECENTLY_KILLED_LIMIT);
+    }
+
+    pub fn recently_killed_items(&self) -> Vec<PickerItem> {
+        self.recently_killed
+            .iter()
+            .map(|killed| PickerItem {
+                label: killed.name.clone(),
+                detail: if killed.path.is_empty() {
+                    "default directory".to_string()
+                } else {
+                    killed.path.clone()
+                },
+            })
+            .collect()
+    }
+
+    pub fn merge_sources(&self) -> Vec<&Session> {
+        let selected_id = self
+            .get_selected_session()
+            .map(|session| session.id.as_str());
+        self.sessions
+            .iter()
+            .filter(|session| Some(session.id.as_str()) != selected_id)
+            .collect()
+    }
+
+    pub fn merge_source_items(&self) -> Vec<PickerItem> {
+        self.merge_sources()
+            .into_iter()
+            .map(|session| PickerItem {
+                label: session.name.clone(),
+                detail: format!("{} window(s)", session.window_count),
+            })
+            .collect()
+    }
+
+    pub fn set_join_sources(&mut self, panes: Vec<PaneLocation>) {
+        let window_id = self.get_selected_window().map(|window| window.id.clone());
+        self.join_sources = panes
+            .into_iter()
+            .filter(|pane| Some(&pane.window_id) != window_id.as_ref())
+            .collect();
+    }
+
+    pub fn join_source_items(&self) -> Vec<PickerItem> {
+        self.join_sources
+            .iter()
+            .map(|pane| PickerItem {
+                label: format!("{} {}", pane.window_name, pane.pane_id),
+                detail: pane.command.clone(),
+            })
+            .collect()
+    }
+
+    pub fn toggle_pane_mark(&mut self) -> Option<bool> {
+        let pane_id = self.get_selected_pane()?.id.clone();
+        let window_id = self.get_selected_window()?.id.clone();
+
+        if self.is_pane_marked(&pane_id) {
+            self.marked_panes.retain(|marked| marked.pane_id != pane_id);
+            Some(false)
+        } else {
+            self.marked_panes.push(MarkedPane { pane_id, window_id });
+            Some(true)
+        }
+    }
+
+    pub fn toggle_pane_watch(&mut self, pane_id: &str, command: &str) -> bool {
+        if self.is_pane_watched(pane_id) {
+            self.watched_panes
+                .retain(|watched| watched.pane_id != pane_id);
+            false
+        } else {
+            self.watched_panes.push(WatchedPane {
+                pane_id: pane_id.to_string(),
+                command: command.to_string(),
+            });
+            true
+        }
+    }
+
+    pub fn pane_title<'a>(&self, pane: &'a Pane) -> Option<&'a str> {
+        let title = pane.title.trim();
+        (!title.is_empty() && Some(title) != self.hostname.as_deref()).then_some(title)
+    }
+
+    pub fn is_pane_watched(&self, pane_id: &str) -> bool {
+        self.watched_panes
+            .iter()
+            .any(|watched| watched.pane_id == pane_id)
+    }
+
+    pub fn is_pane_marked(&self, pane_id: &str) -> bool {
+        self.marked_panes
+            .iter()
+            .any(|marked| marked.pane_id == pane_id)
+    }
+
+    pub fn marked_pane_pair(&self) -> Result<(&str, &str), &'static str> {
+        match self.marked_panes.as_slice() {
+            [first, second] if first.window_id == second.window_id => {
+                Ok((first.pane_id.as_str(), second.pane_id.as_str()))
+            }
+            [_, _] => Err("The two marked panes are in different windows."),
+            [marked] => {
+                let selected_window = self.get_selected_window().map(|window| window.id.as_str());
+                match self.get_selected_pane() {
+                    Some(pane) if pane.id == marked.pane_id => {
+                        Err("Select another pane in the same window to swap with the marked one.")
+                    }
+                    Some(pane) if selected_window == Some(marked.window_id.as_str()) => {
+                        Ok((marked.pane_id.as_str(), pane.id.as_str()))
+                    }
+                    _ => Err("The marked pane is in another window."),
+                }
+            }
+            _ => Err("Mark a pane with Space, then select another or mark a second one."),
+        }
+    }
+
+    pub fn menu_actions(&self) -> &'static [MenuAction] {
+        match self.focus {
+            FocusArea::Sessions => SESSION_ACTIONS,
+            FocusArea::Windows => WINDOW_ACTIONS,
+            FocusArea::Panes => PANE_ACTIONS,
+        }
+    }
+
+    pub fn menu_action_items(&self) -> Vec<PickerItem> {
+        self.menu_actions()
+            .iter()
+            .map(|action| PickerItem {
+                label: action.key_label(),
+                detail: action.label.to_string(),
+            })
+            .collect()
+    }
+
+    pub fn builtin_layout_items(&self) -> Vec<PickerItem> {
+        BUILTIN_LAYOUTS
+            .iter()
+            .map(|name| PickerItem {
+                label: name.to_string(),
+                detail: match *name {
+                    "even-horizontal" => "side by side, equal widths",
+                    "even-vertical" => "stacked, equal heights",
+                    "main-horizontal" => "large pane on top, the rest below",
+                    "main-vertical" => "large pane on the left, the rest beside it",
+                    _ => "a grid of equal panes",
+                }
+                .to_string(),
+            })
+            .collect()
+    }
+
+    pub fn next_builtin_layout(&mut self, window_id: &str) -> &'static str {
+        let next = self
+            .layout_cycle
+            .get(window_id)
+            .map_or(0, |index| (index + 1) % BUILTIN_LAYOUTS.len());
+        self.layout_cycle.insert(window_id.to_string(), next);
+        BUILTIN_LAYOUTS[next]
+    }
+
+    pub fn layout_preset_items(&self) -> Vec<PickerItem> {
+        let pane_count = self.panes.len();
+        self.layout_presets
+            .iter()
+            .map(|preset| {
+                let preset_panes = layout_pane_count(&preset.layout);
+                PickerItem {
+                    label: preset.name.clone(),
+                    detail: if preset_panes == pane_count {
+                        format!("{preset_panes} panes")
+                    } else {
+                        format!("{preset_panes} panes, this window has {pane_count}")
+                    },
+                }
+            })
+            .collect()
+    }
+
+    pub fn last_session_protected(&self) -> bool {
+        self.config.safe_mode && self.sessions.len() == 1
+    }
+
+    pub fn session_created_label(&self, session: &Session) -> String {
+        util::format_timestamp(session.created_at, self.config.time_display)
+            .unwrap_or_else(|| session.created.clone())
+    }
+
+    pub fn socket_items(&self) -> Vec<PickerItem> {
+        self.sockets
+            .iter()
+            .map(|name| PickerItem {
+                label: name.clone(),
+                detail: if Some(name) == self.socket.as_ref() {
+                    "current server".to_string()
+                } else {
+                    String::new()
+                },
+            })
+            .collect()
+    }
+
+    pub fn session_hook_items(&self) -> Vec<PickerItem> {
+        SESSION_HOOKS
+            .iter()
+            .map(|(name, detail)| PickerItem {
+                label: name.to_string(),
+                detail: format!("runs when {detail}"),
+            })
+            .collect()
+    }
+
+    pub fn switch_socket(&mut self, name: Option<String>) {
+        tmux::set_socket(name.clone());
+        self.socket = name;
+        self.marked_panes.clear();
+        self.watched_panes.clear();
+        self.layout_backups.clear();
+        self.buffers.clear();
+        self.clients.clear();
+        self.focus = FocusArea::Sessions;
+        self.clear_filter();
+        self.session_list_state.select(None);
+        self.refresh_all();
+    }
+
+    pub fn client_items(&self) -> Vec<PickerItem> {
+        self.clients
+            .iter()
+            .map(|client| PickerItem {
+                label: client.tty.clone(),
+                detail: format!("{}  {}x{}", client.session, client.width, client.height),
+            })
+            .collect()
+    }
+
+    pub fn buffer_items(&self) -> Vec<PickerItem> {
+        self.buffers
+            .iter()
+            .map(|buffer| PickerItem {
+                label: buffer.name.clone(),
+                detail: format!("{} bytes  {}", buffer.size, buffer.sample),
+            })
+            .collect()
+    }
+
+    pub fn selected_session_path(&self) -> Option<&str> {
+        self.windows
+            .iter()
+            .find(|window| window.active)
+            .or_else(|| self.windows.first())
+            .map(|window| window.path.as_str())
+            .filter(|path| !path.is_empty())
+    }
+
+    pub fn reset_view(&mut self) {
+        self.clear_filter();
+        self.focus = FocusArea::Sessions;
+        let len = self.visible_session_indices().len();
+        select_first(&mut self.session_list_state, len);
+        self.refresh_windows_and_panes(None, None);
+    }
+
+    pub fn cycle_focus(&mut self) {
+        self.focus = match self.focus {
+            FocusArea::Sessions => FocusArea::Windows,
+            FocusArea::Windows => FocusArea::Panes,
+            FocusArea::Panes => FocusArea::Sessions,
+        };
+    }
+
+    pub fn cycle_focus_back(&mut self) {
+        self.focus = match self.focus {
+            FocusArea::Sessions => FocusArea::Panes,
+            FocusArea::Windows => FocusArea::Sessions,
+            FocusArea::Panes => FocusArea::Windows,
+        };
+    }
+
+    pub fn set_info_banner(&mut self, title: impl Into<String>, body: impl Into<String>) {
+        self.needs_redraw = true;
+        self.banner = BannerState::info(
+            truncate_text(&title.into(), 60),
+            truncate_text(&body.into(), 180),
+        );
+    }
+
+    pub fn set_success_banner(&mut self, title: impl Into<String>, body: impl Into<String>) {
+        self.needs_redraw = true;
+        self.banner = BannerState::success(
+            truncate_text(&title.into(), 60),
+            truncate_text(&body.into(), 180),
+        );
+    }
+
+    pub fn set_warning_banner(&mut self, title: impl Into<String>, body: impl Into<String>) {
+        self.needs_redraw = true;
+        self.banner = BannerState::warning(
+            truncate_text(&title.into(), 60),
+            truncate_text(&body.into(), 180),
+        );
+    }
+
+    pub fn set_error_banner(&mut self, title: impl Into<String>, body: impl Into<String>) {
+        self.needs_redraw = true;
+        self.banner = BannerState::error(
+            truncate_text(&title.into(), 60),
+            truncate_text(&body.into(), 180),
+        );
+    }
+
+    pub fn open_filter(&mut self) {
+        let focus = self.focus;
+        self.mutate_filter_preserving_selection(|filter| {
+            if filter.target != focus {
+                filter.query.clear();
+            }
+            filter.target = focus;
+            filter.active = true;
+        });
+    }
+
+    pub fn start_filtered(&mut self, query: &str) {
+        self.focus = FocusArea::Sessions;
+        self.mutate_filter_preserving_selection(|filter| {
+            filter.target = FocusArea::Sessions;
+            filter.query = query.to_string();
+            filter.active = true;
+        });
+    }
+
+    pub fn close_filter(&mut self) {
+        self.filter.active = false;
     }
 
     pub fn clear_filter(&mut self) {
@@ -411,20 +1527,81 @@ impl App {
     }
 
     pub fn open_input_modal(&mut self, intent: InputIntent, value: impl Into<String>) {
+        let value = value.into();
         self.modal = ModalState::Input(InputModalState {
             intent,
-            value: value.into(),
+            cursor: value.chars().count(),
+            value,
             error: None,
+            warning: None,
         });
     }
 
     pub fn open_confirm_modal(&mut self, intent: ConfirmIntent) {
+        let windows = if intent == ConfirmIntent::Session && !self.last_session_protected() {
+            self.windows
+                .iter()
+                .map(|window| window.name.clone())
+                .collect()
+        } else {
+            Vec::new()
+        };
         self.modal = ModalState::Confirm(ConfirmModalState {
             intent,
+            windows,
+            scroll: 0,
+            error: None,
+        });
+    }
+
+    pub fn open_picker_modal(
+        &mut self,
+        intent: PickerIntent,
+        items: Vec<PickerItem>,
+        selected: Option<usize>,
+    ) {
+        let mut list_state = ListState::default();
+        select_first(&mut list_state, items.len());
+        if let Some(selected) = selected.filter(|index| *index < items.len()) {
+            list_state.select(Some(selected));
+        }
+        self.modal = ModalState::Picker(PickerModalState {
+            intent,
+            items,
+            list_state,
+            error: None,
+        });
+    }
+
+    pub fn open_split_modal(&mut self, pane: &Pane) {
+        self.modal = ModalState::Split(SplitModalState {
+            pane_id: pane.id.clone(),
+            width: pane.width,
+            height: pane.height,
+            direction: self.config.split_direction,
+            percent: 50,
             error: None,
         });
     }
 
+    pub fn split_modal_mut(&mut self) -> Option<&mut SplitModalState> {
+        match &mut self.modal {
+            ModalState::Split(modal) => Some(modal),
+            _ => None,
+        }
+    }
+
+    pub fn open_pager(&mut self, pager: PagerState) {
+        self.modal = ModalState::Pager(pager);
+    }
+
+    pub fn pager_mut(&mut self) -> Option<&mut PagerState> {
+        match &mut self.modal {
+            ModalState::Pager(pager) => Some(pager),
+            _ => None,
+        }
+    }
+
     pub fn close_modal(&mut self) {
         self.modal = ModalState::None;
     }
@@ -434,10 +1611,44 @@ impl App {
         match &mut self.modal {
             ModalState::Input(modal) => modal.error = Some(message),
             ModalState::Confirm(modal) => modal.error = Some(message),
-            ModalState::None => self.set_error_banner("Action failed", message),
+            ModalState::Picker(modal) => modal.error = Some(message),
+            ModalState::Split(modal) => modal.error = Some(message),
+            ModalState::Pager(_) | ModalState::None => {
+                self.set_error_banner("Action failed", message)
+            }
+        }
+    }
+
+    pub fn set_modal_warning(&mut self, message: impl Into<String>) {
+        let message = truncate_text(&message.into(), 140);
+        if let ModalState::Input(modal) = &mut self.modal {
+            modal.warning = Some(message);
         }
     }
 
+    pub fn other_attached_clients(&self) -> usize {
+        self.get_selected_session().map_or(0, |session| {
+            let own = self.own_session_id.as_deref() == Some(session.id.as_str());
+            session.attached.saturating_sub(usize::from(own))
+        })
+    }
+
+    pub fn needs_attach_confirmation(&self) -> bool {
+        self.config.confirm_attach && self.other_attached_clients() > 0
+    }
+
+    pub fn session_name_taken(&self, name: &str, except_id: Option<&str>) -> bool {
+        self.sessions
+            .iter()
+            .any(|session| session.name == name && Some(session.id.as_str()) != except_id)
+    }
+
+    pub fn window_name_taken(&self, name: &str, except_id: Option<&str>) -> bool {
+        self.windows
+            .iter()
+            .any(|window| window.name == name && Some(window.id.as_str()) != except_id)
+    }
+
     pub fn input_modal_mut(&mut self) -> Option<&mut InputModalState> {
         match &mut self.modal {
             ModalState::Input(modal) => Some(modal),
@@ -459,6 +1670,72 @@ impl App {
         }
     }
 
+    pub fn confirm_modal_mut(&mut self) -> Option<&mut ConfirmModalState> {
+        match &mut self.modal {
+            ModalState::Confirm(modal) => Some(modal),
+            _ => None,
+        }
+    }
+
+    pub fn picker_modal(&self) -> Option<&PickerModalState> {
+        match &self.modal {
+            ModalState::Picker(modal) => Some(modal),
+            _ => None,
+        }
+    }
+
+    pub fn picker_nav_down(&mut self) {
+        if let ModalState::Picker(modal) = &mut self.modal {
+            next_item(&mut modal.list_state, modal.items.len());
+            modal.error = None;
+        }
+    }
+
+    pub fn picker_nav_up(&mut self) {
+        if let ModalState::Picker(modal) = &mut self.modal {
+            prev_item(&mut modal.list_state, modal.items.len());
+            modal.error = None;
+        }
+    }
+
+    pub fn picker_nav_first(&mut self) {
+        if let ModalState::Picker(modal) = &mut self.modal {
+            select_first(&mut modal.list_state, modal.items.len());
+            modal.error = None;
+        }
+    }
+
+    pub fn picker_nav_last(&mut self) {
+        if let ModalState::Picker(modal) = &mut self.modal {
+            select_last(&mut modal.list_state, modal.items.len());
+            modal.error = None;
+        }
+    }
+
+    pub fn pane_position_items(&self) -> Vec<PickerItem> {
+        let last = self.panes.len().saturating_sub(1);
+        self.panes
+            .iter()
+            .enumerate()
+            .map(|(index, pane)| {
+                let label = match index {
+                    0 => format!("{index}  top-left"),
+                    _ if index == last => format!("{index}  bottom"),
+                    _ => index.to_string(),
+                };
+                PickerItem {
+                    label,
+                    detail: format!("{}  {}", pane.id, pane.current_command),
+                }
+            })
+            .collect()
+    }
+
+    pub fn selected_pane_position(&self) -> Option<usize> {
+        let pane_id = self.selected_pane_id()?;
+        self.panes.iter().position(|pane| pane.id == pane_id)
+    }
+
     pub fn filter_summary(&self) -> Option<String> {
         if self.filter.has_query() {
             Some(format!(
@@ -628,23 +1905,109 @@ impl App {
         selected
     }
 
-    pub(crate) fn visible_session_indices(&self) -> Vec<usize> {
-        visible_indices(
-            &self.sessions,
-            self.active_query(FocusArea::Sessions),
-            |session| format!("{} {} {}", session.name, session.id, session.created),
-        )
+    pub fn select_window_by_id(&mut self, id: &str) -> bool {
+        self.clear_filter_for(FocusArea::Windows);
+        let index = self.windows.iter().position(|window| window.id == id);
+        let selected = self.select_window_by_actual_index(index);
+        if selected {
+            self.refresh_panes_only();
+        }
+        selected
     }
 
-    pub(crate) fn visible_window_indices(&self) -> Vec<usize> {
-        visible_indices(
-            &self.windows,
-            self.active_query(FocusArea::Windows),
-            |window| format!("{} {} {}", window.name, window.id, window.layout),
-        )
+    pub fn advance_command_search(&mut self, query: &str, match_count: usize) -> usize {
+        let position = match &self.command_search {
+            Some(search) if search.query == query && match_count > 0 => {
+                (search.position + 1) % match_count
+            }
+            _ => 0,
+        };
+        self.command_search = Some(CommandSearch {
+            query: query.to_string(),
+            position,
+        });
+        position
     }
 
-    pub(crate) fn visible_pane_indices(&self) -> Vec<usize> {
+    pub fn jump_to_pane(&mut self, location: &PaneLocation) -> bool {
+        self.clear_filter();
+        let index = self
+            .sessions
+            .iter()
+            .position(|session| session.id == location.session_id);
+        if !self.select_session_by_actual_index(index) {
+            return false;
+        }
+        self.refresh_windows_and_panes(Some(&location.window_id), Some(&location.pane_id));
+        self.focus = FocusArea::Panes;
+        self.selected_pane_id() == Some(location.pane_id.as_str())
+    }
+
+    pub fn select_active_window(&mut self) -> bool {
+        let index = self.windows.iter().position(|window| window.active);
+        let selected = self.select_window_by_actual_index(index);
+        if selected {
+            self.refresh_panes_only();
+        }
+        selected
+    }
+
+    pub fn select_active_pane(&mut self) -> bool {
+        let Some(actual_index) = self.panes.iter().position(|pane| pane.active) else {
+            return false;
+        };
+        let visible = self.visible_pane_indices();
+        match visible.iter().position(|index| *index == actual_index) {
+            Some(visible_index) => {
+                self.pane_list_state.select(Some(visible_index));
+                true
+            }
+            None => false,
+        }
+    }
+
+    pub(crate) fn visible_session_indices(&self) -> Vec<usize> {
+        visible_indices(
+            &self.sessions,
+            self.active_query(FocusArea::Sessions),
+            |session| format!("{} {} {}", session.name, session.id, session.created),
+        )
+        .into_iter()
+        .filter(|index| self.session_state_filter.matches(&self.sessions[*index]))
+        .collect()
+    }
+
+    pub fn cycle_session_state_filter(&mut self) {
+        let (selected_session_id, selected_window_id, selected_pane_id) =
+            self.selected_ids_for_reconciliation();
+        self.session_state_filter = self.session_state_filter.next();
+        self.sync_session_selection(selected_session_id.as_deref());
+        self.refresh_windows_and_panes(selected_window_id.as_deref(), selected_pane_id.as_deref());
+    }
+
+    pub fn cycle_session_sort(&mut self) {
+        let selected_session_id = self
+            .get_selected_session()
+            .map(|session| session.id.clone());
+        self.session_sort = self.session_sort.next();
+        self.session_sort.sort(&mut self.sessions);
+        self.sync_session_selection(selected_session_id.as_deref());
+    }
+
+    pub(crate) fn visible_window_indices(&self) -> Vec<usize> {
+        visible_indices(
+            &self.windows,
+            self.active_query(FocusArea::Windows),
+            |window| {
+                format!(
+                    "{} {} {} {}",
+                    window.name, window.id, window.layout, window.path
+                )
+            },
+        )
+    }
+
+    pub(crate) fn visible_pane_indices(&self) -> Vec<usize> {
         visible_indices(&self.panes, self.active_query(FocusArea::Panes), |pane| {
             format!("{} {} {}", pane.id, pane.current_command, pane.current_path)
         })
@@ -701,6 +2064,7 @@ impl App {
         false
     }
 
+    #[cfg(test)]
     fn reconcile_after_filter_change(&mut self) {
         let (selected_session_id, selected_window_id, selected_pane_id) =
             self.selected_ids_for_reconciliation();
@@ -727,7 +2091,8 @@ impl App {
 
     fn selected_ids_for_reconciliation(&self) -> (Option<String>, Option<String>, Option<String>) {
         (
-            self.get_selected_session().map(|session| session.id.clone()),
+            self.get_selected_session()
+                .map(|session| session.id.clone()),
             self.get_selected_window().map(|window| window.id.clone()),
             self.get_selected_pane().map(|pane| pane.id.clone()),
         )
@@ -809,10 +2174,10 @@ impl App {
             }
             Err(err) => {
                 self.connection = TmuxConnectionState::CommandFailed;
-                self.connection_detail = Some(err.to_string());
+                self.connection_detail = Some(format!("{err:#}"));
                 self.clear_windows();
                 self.clear_panes();
-                self.set_error_banner("Could not load windows", err.to_string());
+                self.set_error_banner("Could not load windows", format!("{err:#}"));
                 return;
             }
         }
@@ -820,7 +2185,7 @@ impl App {
         self.refresh_panes(selected_pane_id);
     }
 
-    fn refresh_panes_only(&mut self) {
+    pub fn refresh_panes_only(&mut self) {
         let selected_pane_id = self.get_selected_pane().map(|pane| pane.id.clone());
         self.refresh_panes(selected_pane_id.as_deref());
     }
@@ -835,12 +2200,13 @@ impl App {
             Ok(panes) => {
                 self.panes = panes;
                 self.sync_pane_selection(selected_pane_id);
+                self.refresh_preview();
             }
             Err(err) => {
                 self.connection = TmuxConnectionState::CommandFailed;
-                self.connection_detail = Some(err.to_string());
+                self.connection_detail = Some(format!("{err:#}"));
                 self.clear_panes();
-                self.set_error_banner("Could not load panes", err.to_string());
+                self.set_error_banner("Could not load panes", format!("{err:#}"));
             }
         }
     }
@@ -853,6 +2219,9 @@ impl App {
     fn clear_panes(&mut self) {
         self.panes.clear();
         self.pane_list_state.select(None);
+        if let Some(preview) = self.preview.as_mut() {
+            *preview = PanePreview::default();
+        }
     }
 
     fn reset_banner_for_current_state(&mut self) {
@@ -884,6 +2253,15 @@ impl App {
                     "Press n to create your first session and start tmux.",
                 );
             }
+            TmuxConnectionState::Missing if self.tmux_binary != "tmux" => {
+                self.set_error_banner(
+                    "tmux not found",
+                    format!(
+                        "`{}` could not be run. Check the --tmux path, then restart tmuxui.",
+                        self.tmux_binary
+                    ),
+                );
+            }
             TmuxConnectionState::Missing => {
                 self.set_error_banner("tmux not installed", "Install tmux, then restart tmuxui.");
             }
@@ -913,6 +2291,91 @@ fn action_item(
     }
 }
 
+pub fn validate_name(intent: InputIntent, value: &str) -> Option<&'static str> {
+    let is_session = matches!(
+        intent,
+        InputIntent::NewSession
+            | InputIntent::RenameSession
+            | InputIntent::DuplicateWindow
+            | InputIntent::DevSession
+            | InputIntent::GroupedSession
+            | InputIntent::MoveWindow
+    );
+
+    if value.trim().is_empty() && intent == InputIntent::SessionDirectory {
+        None
+    } else if value.trim().is_empty() {
+        Some(
+            if matches!(
+                intent,
+                InputIntent::AttachCommand
+                    | InputIntent::SessionHook(_)
+                    | InputIntent::FindCommand
+                    | InputIntent::SendKeys
+                    | InputIntent::PipeCommand
+            ) {
+                "Enter a command to continue."
+            } else if intent == InputIntent::WindowsFromDirectory {
+                "Enter a directory to continue."
+            } else if intent == InputIntent::PaneTitle {
+                "Enter a title to continue."
+            } else if intent == InputIntent::KillServer {
+                "Type `yes` to stop the server."
+            } else {
+                "Enter a name to continue."
+            },
+        )
+    } else if value.chars().any(char::is_control) {
+        Some("Names cannot contain line breaks or control characters.")
+    } else if is_session && (value.contains(':') || value.contains('.')) {
+        Some("Session names cannot contain `:` or `.`; tmux uses them in targets.")
+    } else {
+        None
+    }
+}
+
+pub fn preview_lines(raw: &str, limit: usize) -> Vec<String> {
+    let lines: Vec<&str> = raw.trim_end().lines().collect();
+    lines[lines.len().saturating_sub(limit)..]
+        .iter()
+        .map(|line| line.to_string())
+        .collect()
+}
+
+pub const BUILTIN_LAYOUTS: &[&str] = &[
+    "even-horizontal",
+    "even-vertical",
+    "main-horizontal",
+    "main-vertical",
+    "tiled",
+];
+
+pub fn describe_layout(layout: &str) -> String {
+    let panes = layout_pane_count(layout);
+    match (panes, layout.contains('{'), layout.contains('[')) {
+        (0, _, _) => layout.to_string(),
+        (1, _, _) => "1 pane".to_string(),
+        (_, true, true) => format!("{panes} panes, mixed"),
+        (_, true, false) => format!("{panes} panes side by side"),
+        (_, false, _) => format!("{panes} panes stacked"),
+    }
+}
+
+pub fn equalized_layout(layout: &str) -> Option<&'static str> {
+    match (layout.contains('{'), layout.contains('[')) {
+        (true, true) => Some("tiled"),
+        (true, false) => Some("even-horizontal"),
+        (false, true) => Some("even-vertical"),
+        (false, false) => None,
+    }
+}
+
+pub fn layout_pane_count(layout: &str) -> usize {
+    let cells = layout.matches('x').count();
+    let containers = layout.matches(['{', '[']).count();
+    cells.saturating_sub(containers)
+}
+
 fn visible_indices<T, F>(items: &[T], query: Option<&str>, make_text: F) -> Vec<usize>
 where
     F: Fn(&T) -> String,
@@ -931,6 +2394,51 @@ where
     }
 }
 
+#[cfg(feature = "regex")]
+fn matching_lines(lines: &[String], query: &str) -> (Vec<usize>, Option<String>) {
+    if query.is_empty() {
+        return ((0..lines.len()).collect(), None);
+    }
+
+    match regex::RegexBuilder::new(query)
+        .case_insensitive(true)
+        .build()
+    {
+        Ok(pattern) => (
+            lines
+                .iter()
+                .enumerate()
+                .filter(|(_, line)| pattern.is_match(line))
+                .map(|(index, _)| index)
+                .collect(),
+            None,
+        ),
+        Err(_) => (
+            substring_matches(lines, query),
+            Some("Not a valid regex, matching plain text instead.".to_string()),
+        ),
+    }
+}
+
+#[cfg(not(feature = "regex"))]
+fn matching_lines(lines: &[String], query: &str) -> (Vec<usize>, Option<String>) {
+    if query.is_empty() {
+        return ((0..lines.len()).collect(), None);
+    }
+
+    (substring_matches(lines, query), None)
+}
+
+fn substring_matches(lines: &[String], query: &str) -> Vec<usize> {
+    let needle = query.to_ascii_lowercase();
+    lines
+        .iter()
+        .enumerate()
+        .filter(|(_, line)| line.to_ascii_lowercase().contains(&needle))
+        .map(|(index, _)| index)
+        .collect()
+}
+
 fn selected_visible_index(selected: Option<usize>, visible_indices: &[usize]) -> Option<usize> {
     selected.and_then(|index| visible_indices.get(index).copied())
 }
@@ -962,6 +2470,25 @@ fn prev_item(state: &mut ListState, len: usize) {
     state.select(Some(next));
 }
 
+fn move_selection(state: &mut ListState, len: usize, step: usize, down: bool) {
+    if len == 0 {
+        state.select(None);
+        return;
+    }
+
+    let current = state.selected().unwrap_or(0);
+    let next = if down {
+        (current + step).min(len - 1)
+    } else {
+        current.saturating_sub(step)
+    };
+    state.select(Some(next));
+}
+
+fn contains(area: Rect, column: u16, row: u16) -> bool {
+    column >= area.x && column < area.x + area.width && row >= area.y && row < area.y + area.height
+}
+
 fn select_first(state: &mut ListState, len: usize) {
     if len == 0 {
         state.select(None);
@@ -992,17 +2519,21 @@ fn select_matching_visible<T, F>(
         return;
     }
 
-    if let Some(preferred_id) = preferred_id {
-        if let Some(visible_index) = visible_indices
-            .iter()
-            .position(|index| get_id(&items[*index]) == preferred_id)
-        {
-            state.select(Some(visible_index));
-            return;
+    let Some(preferred_id) = preferred_id else {
+        state.select(Some(0));
+        return;
+    };
+
+    match visible_indices
+        .iter()
+        .position(|index| get_id(&items[*index]) == preferred_id)
+    {
+        Some(visible_index) => state.select(Some(visible_index)),
+        None => {
+            let clamped = state.selected().unwrap_or(0).min(visible_indices.len() - 1);
+            state.select(Some(clamped));
         }
     }
-
-    state.select(Some(0));
 }
 
 fn truncate_text(text: &str, limit: usize) -> String {
@@ -1018,6 +2549,7 @@ fn truncate_text(text: &str, limit: usize) -> String {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::config::TimeDisplay;
 
     fn sample_app() -> App {
         let mut app = App {
@@ -1027,12 +2559,20 @@ mod tests {
                     name: "dev".to_string(),
                     window_count: 2,
                     created: "Sun Apr 19 12:00:00 2026".to_string(),
+                    created_at: 1_776_600_000,
+                    last_activity: 1_776_600_000,
+                    attached: 1,
+                    destroy_unattached: false,
                 },
                 Session {
                     id: "%1".to_string(),
                     name: "ops".to_string(),
                     window_count: 1,
                     created: "Sun Apr 19 13:00:00 2026".to_string(),
+                    created_at: 1_776_603_600,
+                    last_activity: 1_776_603_600,
+                    attached: 0,
+                    destroy_unattached: false,
                 },
             ],
             windows: vec![
@@ -1041,30 +2581,46 @@ mod tests {
                     name: "editor".to_string(),
                     active: true,
                     layout: "main-vertical".to_string(),
+                    path: "/tmp/project".to_string(),
+                    aggressive_resize: false,
+                    synchronized: false,
                 },
                 Window {
                     id: "@2".to_string(),
                     name: "logs".to_string(),
                     active: false,
                     layout: "tiled".to_string(),
+                    path: "/var/log".to_string(),
+                    aggressive_resize: false,
+                    synchronized: false,
                 },
             ],
             panes: vec![
                 Pane {
                     id: "%10".to_string(),
+                    index: 0,
                     width: 120,
                     height: 30,
                     current_path: "/tmp/project".to_string(),
                     current_command: "nvim".to_string(),
                     active: true,
+                    zoomed: false,
+                    history_size: 1200,
+                    history_limit: 2000,
+                    title: "buildbox".to_string(),
                 },
                 Pane {
                     id: "%11".to_string(),
+                    index: 1,
                     width: 120,
                     height: 30,
                     current_path: "/tmp/project".to_string(),
                     current_command: "cargo test".to_string(),
                     active: false,
+                    zoomed: false,
+                    history_size: 0,
+                    history_limit: 2000,
+                    title: "tests".to_string(),
                 },
             ],
             ..App::default()
@@ -1099,6 +2655,118 @@ mod tests {
         assert_eq!(app.selected_session_name(), Some("ops"));
     }
 
+    #[test]
+    fn moving_while_filtering_stays_within_the_matches() {
+        let mut app = sample_app();
+        let mut api = app.sessions[0].clone();
+        api.id = "%2".to_string();
+        api.name = "dev-api".to_string();
+        app.sessions.push(api);
+
+        app.open_filter();
+        for character in "dev".chars() {
+            app.push_filter_char(character);
+        }
+        assert_eq!(app.visible_session_indices(), vec![0, 2]);
+
+        app.nav_down();
+        assert_eq!(app.selected_session_name(), Some("dev-api"));
+        app.nav_down();
+        assert_eq!(app.selected_session_name(), Some("dev"));
+        assert!(app.filter.active);
+    }
+
+    #[test]
+    fn reordered_sessions_keep_the_selected_id() {
+        let mut app = sample_app();
+        app.session_list_state.select(Some(1));
+
+        app.sessions.reverse();
+        app.sync_session_selection(Some("%1"));
+
+        assert_eq!(app.session_list_state.selected(), Some(0));
+        assert_eq!(app.selected_session_name(), Some("ops"));
+    }
+
+    #[test]
+    fn removed_selection_falls_back_to_the_same_position() {
+        let mut app = sample_app();
+        let mut api = app.sessions[0].clone();
+        api.id = "%2".to_string();
+        api.name = "api".to_string();
+        app.sessions.push(api);
+        app.session_list_state.select(Some(1));
+
+        app.sessions.remove(1);
+        app.sync_session_selection(Some("%1"));
+        assert_eq!(app.selected_session_name(), Some("api"));
+
+        app.sessions.remove(1);
+        app.sync_session_selection(Some("%2"));
+        assert_eq!(app.selected_session_name(), Some("dev"));
+
+        app.sync_session_selection(None);
+        assert_eq!(app.session_list_state.selected(), Some(0));
+    }
+
+    #[test]
+    fn input_edits_happen_at_the_cursor() {
+        let mut app = sample_app();
+        app.open_input_modal(InputIntent::RenameSession, "wrk");
+        let modal = app.input_modal_mut().expect("input modal should be open");
+        assert_eq!(modal.cursor, 3);
+
+        modal.move_left();
+        modal.move_left();
+        modal.insert('o');
+        assert_eq!((modal.value.as_str(), modal.cursor), ("work", 2));
+
+        modal.move_end();
+        modal.insert('é');
+        modal.insert('s');
+        modal.move_left();
+        modal.backspace();
+        assert_eq!((modal.value.as_str(), modal.cursor), ("works", 4));
+
+        modal.move_home();
+        modal.delete();
+        modal.move_left();
+        assert_eq!((modal.value.as_str(), modal.cursor), ("orks", 0));
+
+        modal.move_end();
+        modal.move_right();
+        modal.delete();
+        assert_eq!((modal.value.as_str(), modal.cursor), ("orks", 4));
+    }
+
+    #[test]
+    fn sorting_sessions_keeps_the_selected_session() {
+        let mut app = sample_app();
+        app.sessions[0].last_activity = 1_776_700_000;
+        app.session_list_state.select(Some(1));
+
+        app.cycle_session_sort();
+        assert_eq!(app.session_sort, SessionSort::Created);
+        assert_eq!(app.sessions[0].name, "dev");
+        assert_eq!(app.selected_session_name(), Some("ops"));
+
+        app.cycle_session_sort();
+        assert_eq!(app.session_sort, SessionSort::Activity);
+        assert_eq!(app.sessions[0].name, "dev");
+        assert_eq!(app.selected_session_name(), Some("ops"));
+
+        app.sessions[0].last_activity = 0;
+        app.session_sort = SessionSort::Created;
+        app.cycle_session_sort();
+        assert_eq!(app.sessions[0].name, "ops");
+        assert_eq!(app.session_list_state.selected(), Some(0));
+
+        app.cycle_session_sort();
+        assert_eq!(app.session_sort, SessionSort::Name);
+        assert_eq!(app.sessions[0].name, "dev");
+        assert_eq!(app.selected_session_name(), Some("ops"));
+    }
+
     #[test]
     fn clearing_session_filter_preserves_selected_session() {
         let mut app = sample_app();
@@ -1171,6 +2839,371 @@ mod tests {
         assert!(!app.filter.active);
     }
 
+    #[test]
+    fn pane_position_picker_labels_ends_and_preselects_current_pane() {
+        let mut app = sample_app();
+        app.pane_list_state.select(Some(1));
+
+        let items = app.pane_position_items();
+        app.open_picker_modal(
+            PickerIntent::PanePosition,
+            items,
+            app.selected_pane_position(),
+        );
+
+        let modal = app.picker_modal().expect("picker should be open");
+        assert_eq!(modal.items[0].label, "0  top-left");
+        assert_eq!(modal.items[1].label, "1  bottom");
+        assert_eq!(modal.selected_index(), Some(1));
+
+        app.picker_nav_down();
+        assert_eq!(app.picker_modal().and_then(|m| m.selected_index()), Some(0));
+    }
+
+    #[test]
+    fn select_active_pane_follows_tmux_active_flag() {
+        let mut app = sample_app();
+        app.panes[0].active = false;
+        app.panes[1].active = true;
+
+        assert!(app.select_active_pane());
+        assert_eq!(app.selected_pane_id(), Some("%11"));
+    }
+
+    #[test]
+    fn start_filtered_seeds_the_sessions_filter() {
+        let mut app = sample_app();
+        app.focus = FocusArea::Panes;
+
+        app.start_filtered("ops");
+
+        assert_eq!(app.focus, FocusArea::Sessions);
+        assert!(app.filter.active);
+        assert_eq!(app.visible_session_indices(), vec![1]);
+        assert_eq!(app.selected_session_name(), Some("ops"));
+    }
+
+    #[test]
+    fn split_sizes_leave_room_for_the_divider_and_clamp_percentages() {
+        assert_eq!(split_sizes(31, 30), (21, 9));
+        assert_eq!(split_sizes(30, 50), (14, 15));
+        assert_eq!(split_sizes(0, 50), (0, 0));
+
+        let mut app = sample_app();
+        let pane = app.panes[0].clone();
+        app.open_split_modal(&pane);
+        let modal = app.split_modal_mut().expect("split modal should be open");
+        for _ in 0..20 {
+            modal.grow();
+        }
+        assert_eq!(modal.percent, 90);
+        for _ in 0..20 {
+            modal.shrink();
+        }
+        assert_eq!(modal.percent, 10);
+    }
+
+    #[test]
+    fn split_direction_starts_from_the_config_and_picks_the_axis() {
+        let mut app = sample_app();
+        app.config.split_direction = Some(SplitDirection::Horizontal);
+        let pane = app.panes[0].clone();
+
+        app.open_split_modal(&pane);
+        let modal = app.split_modal_mut().expect("split modal should be open");
+        assert_eq!(modal.direction, Some(SplitDirection::Horizontal));
+        assert_eq!(modal.sizes(), (59, 60));
+
+        modal.choose(SplitDirection::Vertical);
+        assert_eq!(modal.sizes(), (14, 15));
+    }
+
+    #[test]
+    fn toggling_a_watch_adds_and_removes_it() {
+        let mut app = sample_app();
+
+        assert!(app.toggle_pane_watch("%11", "cargo test"));
+        assert!(app.is_pane_watched("%11"));
+        assert!(!app.toggle_pane_watch("%11", "cargo test"));
+        assert!(app.watched_panes.is_empty());
+    }
+
+    #[test]
+    fn equalized_layout_follows_the_split_directions() {
+        assert_eq!(
+            equalized_layout("b1e2,100x40,0,0{50x40,0,0,0,49x40,51,0,1}"),
+            Some("even-horizontal")
+        );
+        assert_eq!(
+            equalized_layout("c3d4,100x40,0,0[100x20,0,0,0,100x19,0,21,1]"),
+            Some("even-vertical")
+        );
+        assert_eq!(
+            equalized_layout("a1b2,100x40,0,0{50x40,0,0[50x20,0,0,0,50x19,0,21,2],49x40,51,0,1}"),
+            Some("tiled")
+        );
+        assert_eq!(equalized_layout("d5e6,100x40,0,0,0"), None);
+    }
+
+    #[test]
+    fn session_delete_confirmation_lists_its_windows() {
+        let mut app = sample_app();
+
+        app.open_confirm_modal(ConfirmIntent::Session);
+        let modal = app.confirm_modal_mut().expect("confirm should be open");
+        assert_eq!(modal.windows, vec!["editor", "logs"]);
+        modal.scroll_down();
+        modal.scroll_down();
+        assert_eq!(modal.scroll, 1);
+
+        app.open_confirm_modal(ConfirmIntent::Pane);
+        assert!(app
+            .confirm_modal()
+            .expect("confirm should be open")
+            .windows
+            .is_empty());
+    }
+
+    #[test]
+    fn window_neighbor_stops_at_the_ends() {
+        let mut app = sample_app();
+        app.focus = FocusArea::Windows;
+
+        assert!(app.window_neighbor(false).is_none());
+        assert_eq!(
+            app.window_neighbor(true).map(|w| w.name.as_str()),
+            Some("logs")
+        );
+        app.nav_down();
+        assert!(app.window_neighbor(true).is_none());
+        assert_eq!(
+            app.window_neighbor(false).map(|w| w.name.as_str()),
+            Some("editor")
+        );
+    }
+
+    #[test]
+    fn merge_sources_skip_the_selected_session() {
+        let app = sample_app();
+
+        let items = app.merge_source_items();
+
+        assert_eq!(items.len(), app.sessions.len() - 1);
+        assert!(items
+            .iter()
+            .all(|item| Some(item.label.as_str()) != app.selected_session_name()));
+    }
+
+    #[test]
+    fn join_sources_skip_the_selected_window() {
+        let mut app = sample_app();
+        let pane = |window_id: &str, window_name: &str, pane_id: &str| PaneLocation {
+            session_id: "%0".to_string(),
+            session_name: "dev".to_string(),
+            window_id: window_id.to_string(),
+            window_name: window_name.to_string(),
+            pane_id: pane_id.to_string(),
+            command: "zsh".to_string(),
+        };
+
+        app.set_join_sources(vec![
+            pane("@1", "editor", "%10"),
+            pane("@1", "editor", "%11"),
+            pane("@2", "logs", "%20"),
+        ]);
+        let items = app.join_source_items();
+
+        assert_eq!(items.len(), 1);
+        assert_eq!(items[0].label, "logs %20");
+        assert_eq!(items[0].detail, "zsh");
+    }
+
+    #[test]
+    fn repeated_command_search_cycles_through_matches() {
+        let mut app = sample_app();
+
+        assert_eq!(app.advance_command_search("node", 3), 0);
+        assert_eq!(app.advance_command_search("node", 3), 1);
+        assert_eq!(app.advance_command_search("node", 3), 2);
+        assert_eq!(app.advance_command_search("node", 3), 0);
+        assert_eq!(app.advance_command_search("cargo", 2), 0);
+    }
+
+    #[test]
+    fn preview_keeps_the_last_lines_without_trailing_blanks() {
+        let raw = "one\ntwo\nthree\nfour\n\n\n";
+
+        assert_eq!(preview_lines(raw, 2), vec!["three", "four"]);
+        assert_eq!(preview_lines(raw, 10).len(), 4);
+        assert!(preview_lines("", 10).is_empty());
+    }
+
+    #[test]
+    fn pane_navigation_queues_a_preview_refresh_only_when_shown() {
+        let mut app = sample_app();
+        app.focus = FocusArea::Panes;
+
+        app.nav_down();
+        assert_eq!(app.pending_refresh, None);
+
+        app.preview = Some(PanePreview::default());
+        app.nav_up();
+        assert_eq!(app.pending_refresh, Some(PendingRefresh::Preview));
+
+        app.queue_refresh(PendingRefresh::Panes);
+        app.queue_refresh(PendingRefresh::Preview);
+        assert_eq!(app.pending_refresh, Some(PendingRefresh::Panes));
+    }
+
+    #[test]
+    fn attach_confirmation_ignores_tmuxuis_own_client() {
+        let mut app = sample_app();
+        assert!(app.needs_attach_confirmation());
+
+        app.own_session_id = Some("%0".to_string());
+        assert!(!app.needs_attach_confirmation());
+
+        app.own_session_id = None;
+        app.config.confirm_attach = false;
+        assert!(!app.needs_attach_confirmation());
+
+        app.config.confirm_attach = true;
+        app.session_list_state.select(Some(1));
+        assert!(!app.needs_attach_confirmation());
+    }
+
+    #[test]
+    fn action_menu_follows_focus() {
+        let mut app = sample_app();
+
+        let items = app.menu_action_items();
+        assert_eq!(items[0].label, "Enter");
+        assert!(items.iter().any(|item| item.detail == "Rename session"));
+
+        app.focus = FocusArea::Panes;
+        let items = app.menu_action_items();
+        assert!(items.iter().any(|item| item.label == "Space"));
+        assert!(items.iter().any(|item| item.label == "Ctrl+O"));
+        assert!(items
+            .iter()
+            .any(|item| item.label == "R" && item.detail == "Set the pane title"));
+    }
+
+    #[test]
+    fn reset_view_returns_to_first_session() {
+        let mut app = sample_app();
+        app.focus = FocusArea::Panes;
+        app.session_list_state.select(Some(1));
+        app.filter.target = FocusArea::Sessions;
+        app.filter.query = "ops".to_string();
+
+        app.reset_view();
+
+        assert_eq!(app.focus, FocusArea::Sessions);
+        assert!(app.filter.query.is_empty());
+        assert_eq!(app.selected_session_name(), Some("dev"));
+    }
+
+    #[test]
+    fn name_collisions_ignore_the_item_being_renamed() {
+        let app = sample_app();
+
+        assert!(app.session_name_taken("ops", Some("%0")));
+        assert!(!app.session_name_taken("dev", Some("%0")));
+        assert!(app.window_name_taken("logs", Some("@1")));
+        assert!(!app.window_name_taken("logs", Some("@2")));
+    }
+
+    #[test]
+    fn selected_path_falls_back_to_the_window_path() {
+        let mut app = sample_app();
+        assert_eq!(app.selected_path(), Some("/tmp/project"));
+
+        app.panes.clear();
+        app.pane_list_state.select(None);
+        app.window_list_state.select(Some(1));
+        assert_eq!(app.selected_path(), Some("/var/log"));
+    }
+
+    #[test]
+    fn banner_changes_request_a_redraw() {
+        let mut app = sample_app();
+        app.needs_redraw = false;
+
+        app.set_success_banner("Done", "Nothing else to do.");
+
+        assert!(app.needs_redraw);
+    }
+
+    #[test]
+    fn pager_opens_at_the_bottom_and_clamps_scrolling() {
+        let content = (0..10)
+            .map(|index| format!("line {index}"))
+            .collect::<Vec<_>>()
+            .join("\n");
+        let mut pager = PagerState::new("Scrollback", &content);
+        pager.set_viewport_height(4);
+
+        assert_eq!(pager.scroll, 6);
+        pager.scroll_down(5);
+        assert_eq!(pager.scroll, 6);
+        pager.scroll_up(10);
+        assert_eq!(pager.scroll, 0);
+        assert_eq!(
+            pager.visible_lines().collect::<Vec<_>>(),
+            vec!["line 0", "line 1", "line 2", "line 3"]
+        );
+    }
+
+    #[test]
+    fn pager_search_keeps_only_matching_lines() {
+        let mut pager = PagerState::new("Scrollback", "ok build\nERROR disk\nok test\nerror net");
+        pager.set_viewport_height(10);
+
+        for character in "error".chars() {
+            pager.push_query_char(character);
+        }
+
+        assert_eq!(pager.visible, vec![1, 3]);
+
+        pager.clear_query();
+        assert_eq!(pager.visible.len(), 4);
+    }
+
+    #[cfg(feature = "regex")]
+    #[test]
+    fn pager_search_uses_regex_and_falls_back_on_invalid_patterns() {
+        let lines = vec!["GET /a 200".to_string(), "GET /b 500".to_string()];
+
+        assert_eq!(matching_lines(&lines, r"5\d\d$"), (vec![1], None));
+
+        let (visible, error) = matching_lines(&lines, "(200");
+        assert!(visible.is_empty());
+        assert!(error.is_some());
+    }
+
+    #[test]
+    fn recently_killed_sessions_are_newest_first_and_unique() {
+        let mut app = sample_app();
+
+        app.remember_killed_session("api", "/srv/api");
+        app.remember_killed_session("web", "/srv/web");
+        app.remember_killed_session("api", "/srv/api-v2");
+
+        let names: Vec<&str> = app
+            .recently_killed
+            .iter()
+            .map(|killed| killed.name.as_str())
+            .collect();
+        assert_eq!(names, vec!["api", "web"]);
+        assert_eq!(app.recently_killed[0].path, "/srv/api-v2");
+
+        for index in 0..20 {
+            app.remember_killed_session(format!("s{index}"), "");
+        }
+        assert_eq!(app.recently_killed.len(), RECENTLY_KILLED_LIMIT);
+    }
+
     #[test]
     fn truncates_banner_copy_for_safe_rendering() {
         let long = "x".repeat(220);
@@ -1179,4 +3212,309 @@ mod tests {
         assert!(truncated.len() <= 120);
         assert!(truncated.ends_with("..."));
     }
+
+    #[test]
+    fn validate_name_allows_spaces_but_rejects_target_separators() {
+        assert_eq!(validate_name(InputIntent::NewSession, "my work"), None);
+        assert_eq!(validate_name(InputIntent::RenameWindow, "build logs"), None);
+        assert_eq!(validate_name(InputIntent::RenameWindow, "v1.2: api"), None);
+        assert!(validate_name(InputIntent::NewSession, "   ").is_some());
+        assert!(validate_name(InputIntent::NewSession, "api:v2").is_some());
+        assert!(validate_name(InputIntent::RenameSession, "release.1").is_some());
+        assert!(validate_name(InputIntent::NewWindow, "tab\tname").is_some());
+        assert_eq!(
+            validate_name(InputIntent::WindowsFromDirectory, " "),
+            Some("Enter a directory to continue.")
+        );
+        assert_eq!(
+            validate_name(InputIntent::WindowsFromDirectory, "~/src/my.app"),
+            None
+        );
+        assert_eq!(validate_name(InputIntent::SessionDirectory, ""), None);
+        assert_eq!(
+            validate_name(InputIntent::AttachCommand, "git status"),
+            None
+        );
+        assert_eq!(
+            validate_name(InputIntent::AttachCommand, ""),
+            Some("Enter a command to continue.")
+        );
+    }
+
+    #[test]
+    fn buffer_items_show_size_and_sample() {
+        let mut app = sample_app();
+        app.buffers = vec![Buffer {
+            name: "buffer0".to_string(),
+            size: 12,
+            sample: "cargo test\\n".to_string(),
+        }];
+
+        let items = app.buffer_items();
+
+        assert_eq!(items[0].label, "buffer0");
+        assert_eq!(items[0].detail, "12 bytes  cargo test\\n");
+    }
+
+    #[test]
+    fn session_created_label_follows_time_display() {
+        let mut app = sample_app();
+        let session = app.sessions[0].clone();
+
+        assert_eq!(app.session_created_label(&session), session.created);
+
+        app.config.time_display = TimeDisplay::Utc;
+        assert_eq!(
+            app.session_created_label(&session),
+            "Sun Apr 19 12:00:00 2026"
+        );
+
+        app.sessions[0].created_at = -1;
+        app.config.time_display = TimeDisplay::Local;
+        assert!(!app.session_created_label(&app.sessions[0]).is_empty());
+    }
+
+    #[test]
+    fn pager_focus_only_moves_to_the_list_for_pane_scrollback() {
+        let mut pager = PagerState::new("Keys", "a\nb");
+        pager.toggle_focus();
+        assert!(pager.preview_focused);
+
+        pager.show_pane("%11", "error: one\nok\nerror: two");
+        pager.toggle_focus();
+        assert!(!pager.preview_focused);
+        pager.toggle_focus();
+        assert!(pager.preview_focused);
+    }
+
+    #[test]
+    fn pager_keeps_its_search_when_switching_panes() {
+        let mut pager = PagerState::new("Scrollback %10", "error: first\nok");
+        pager.push_query_char('e');
+        pager.push_query_char('r');
+
+        pager.show_pane("%11", "error: one\nok\nerror: two\nerr again");
+
+        assert_eq!(pager.title, "Scrollback %11");
+        assert_eq!(pager.query, "er");
+        assert_eq!(pager.visible, vec![0, 2, 3]);
+    }
+
+    #[test]
+    fn session_state_filter_cycles_and_maps_back_to_real_sessions() {
+        let mut app = sample_app();
+        app.session_list_state.select(Some(1));
+
+        app.cycle_session_state_filter();
+        assert_eq!(app.session_state_filter, SessionStateFilter::Attached);
+        assert_eq!(app.visible_session_indices(), vec![0]);
+        assert_eq!(app.selected_session_name(), Some("dev"));
+
+        app.cycle_session_state_filter();
+        assert_eq!(app.session_state_filter, SessionStateFilter::Detached);
+        assert_eq!(app.visible_session_indices(), vec![1]);
+        assert_eq!(app.selected_session_name(), Some("ops"));
+
+        app.cycle_session_state_filter();
+        assert_eq!(app.session_state_filter, SessionStateFilter::All);
+        assert_eq!(app.visible_session_indices(), vec![0, 1]);
+    }
+
+    #[test]
+    fn navigating_a_thousand_sessions_defers_detail_refresh() {
+        let mut app = sample_app();
+        app.sessions = (0..1000)
+            .map(|index| Session {
+                id: format!("${index}"),
+                name: format!("ci-{index}"),
+                window_count: 1,
+                created: "Sun Apr 19 12:00:00 2026".to_string(),
+                created_at: 1_776_600_000,
+                last_activity: 1_776_600_000,
+                attached: index % 2,
+                destroy_unattached: false,
+            })
+            .collect();
+        app.session_list_state.select(Some(0));
+
+        for _ in 0..999 {
+            app.nav_down();
+        }
+        app.nav_up();
+        app.nav_last();
+
+        assert_eq!(app.selected_session_name(), Some("ci-999"));
+        assert_eq!(app.pending_refresh, Some(PendingRefresh::WindowsAndPanes));
+
+        app.focus = FocusArea::Windows;
+        app.nav_down();
+        assert_eq!(app.pending_refresh, Some(PendingRefresh::WindowsAndPanes));
+    }
+
+    #[test]
+    fn half_page_jumps_stop_at_the_list_ends() {
+        let mut app = sample_app();
+        app.sessions = (0..30)
+            .map(|index| Session {
+                id: format!("${index}"),
+                name: format!("ci-{index}"),
+                ..app.sessions[0].clone()
+            })
+            .collect();
+        app.list_rows = 20;
+
+        app.nav_half_page(true);
+        assert_eq!(app.selected_session_name(), Some("ci-10"));
+        app.nav_half_page(true);
+        app.nav_half_page(true);
+        assert_eq!(app.selected_session_name(), Some("ci-29"));
+        app.nav_half_page(false);
+        assert_eq!(app.selected_session_name(), Some("ci-19"));
+
+        app.focus = FocusArea::Panes;
+        app.nav_half_page(true);
+        assert_eq!(app.pane_list_state.selected(), Some(1));
+        app.nav_half_page(false);
+        assert_eq!(app.pane_list_state.selected(), Some(0));
+    }
+
+    #[test]
+    fn clicks_focus_the_column_and_select_the_row_under_them() {
+        let mut app = sample_app();
+        app.panel_areas = vec![
+            (FocusArea::Sessions, Rect::new(0, 2, 40, 10)),
+            (FocusArea::Windows, Rect::new(40, 2, 40, 10)),
+            (FocusArea::Panes, Rect::new(80, 2, 40, 10)),
+        ];
+
+        assert_eq!(app.click_list(45, 4), Some((FocusArea::Windows, 1)));
+        assert_eq!(app.focus, FocusArea::Windows);
+        assert_eq!(app.selected_window_name(), Some("logs"));
+
+        assert_eq!(app.click_list(90, 5), Some((FocusArea::Panes, 1)));
+        assert_eq!(app.selected_pane_id(), Some("%11"));
+
+        assert_eq!(app.click_list(5, 2), None);
+        assert_eq!(app.focus, FocusArea::Sessions);
+        assert_eq!(app.click_list(5, 9), None);
+    }
+
+    #[test]
+    fn scrolling_moves_the_hovered_column_without_taking_focus() {
+        let mut app = sample_app();
+        app.panel_areas = vec![
+            (FocusArea::Sessions, Rect::new(0, 2, 40, 10)),
+            (FocusArea::Windows, Rect::new(40, 2, 40, 10)),
+        ];
+
+        assert!(app.scroll_list(50, 6, true));
+        assert_eq!(app.focus, FocusArea::Sessions);
+        assert_eq!(app.selected_window_name(), Some("logs"));
+        assert!(app.scroll_list(50, 6, true));
+        assert_eq!(app.selected_window_name(), Some("editor"));
+
+        assert!(!app.scroll_list(100, 6, true));
+    }
+
+    #[test]
+    fn describes_layouts_by_pane_count_and_direction() {
+        assert_eq!(describe_layout("b25f,80x24,0,0,2"), "1 pane");
+        assert_eq!(
+            describe_layout("b1e2,100x40,0,0{50x40,0,0,0,49x40,51,0,1}"),
+            "2 panes side by side"
+        );
+        assert_eq!(
+            describe_layout("c1e2,100x40,0,0[100x20,0,0,0,100x19,0,21,1]"),
+            "2 panes stacked"
+        );
+        assert_eq!(
+            describe_layout("489f,100x40,0,0{50x40,0,0,0,49x40,51,0[49x20,51,0,1,49x19,51,21,2]}"),
+            "3 panes, mixed"
+        );
+        assert_eq!(describe_layout("tiled"), "tiled");
+    }
+
+    #[test]
+    fn pane_titles_hide_tmuxs_hostname_default() {
+        let mut app = sample_app();
+        app.hostname = Some("buildbox".to_string());
+
+        assert_eq!(app.pane_title(&app.panes[0]), None);
+        assert_eq!(app.pane_title(&app.panes[1]), Some("tests"));
+    }
+
+    #[test]
+    fn cycling_layouts_walks_the_builtins_per_window() {
+        let mut app = sample_app();
+
+        assert_eq!(app.next_builtin_layout("@1"), "even-horizontal");
+        assert_eq!(app.next_builtin_layout("@1"), "even-vertical");
+        assert_eq!(app.next_builtin_layout("@2"), "even-horizontal");
+        for _ in 0..3 {
+            app.next_builtin_layout("@1");
+        }
+        assert_eq!(app.next_builtin_layout("@1"), "even-horizontal");
+    }
+
+    #[test]
+    fn layout_preset_items_compare_pane_counts() {
+        let mut app = sample_app();
+        app.layout_presets = vec![
+            LayoutPreset {
+                name: "pair".to_string(),
+                layout: "b1e2,100x40,0,0{50x40,0,0,0,49x40,51,0,1}".to_string(),
+            },
+            LayoutPreset {
+                name: "trio".to_string(),
+                layout: "489f,100x40,0,0{50x40,0,0,0,49x40,51,0[49x20,51,0,1,49x19,51,21,2]}"
+                    .to_string(),
+            },
+        ];
+
+        let items = app.layout_preset_items();
+
+        assert_eq!(items[0].detail, "2 panes");
+        assert_eq!(items[1].detail, "3 panes, this window has 2");
+    }
+
+    #[test]
+    fn colored_scrollback_searches_the_plain_text() {
+        let mut pager = PagerState::new("Scrollback %11", "");
+        pager.ansi = true;
+        pager.show_pane(
+            "%11",
+            "\u{1b}[32mok\u{1b}[0m\n\u{1b}[31merror\u{1b}[0m: boom",
+        );
+        pager.push_query_char('e');
+        pager.push_query_char('r');
+
+        assert_eq!(pager.lines[1], "error: boom");
+        assert_eq!(pager.visible, vec![1]);
+        assert!(pager
+            .ansi_lines
+            .as_ref()
+            .is_some_and(|lines| lines[1].contains('\u{1b}')));
+    }
+
+    #[test]
+    fn swapping_pairs_marked_panes_in_one_window() {
+        let mut app = sample_app();
+        app.pane_list_state.select(Some(0));
+        assert_eq!(app.toggle_pane_mark(), Some(true));
+        assert!(app.marked_pane_pair().is_err());
+
+        app.pane_list_state.select(Some(1));
+        assert_eq!(app.marked_pane_pair(), Ok(("%10", "%11")));
+        app.toggle_pane_mark();
+        assert_eq!(app.marked_pane_pair(), Ok(("%10", "%11")));
+
+        app.marked_panes[1].window_id = "@9".to_string();
+        assert_eq!(
+            app.marked_pane_pair(),
+            Err("The two marked panes are in different windows.")
+        );
+
+        assert_eq!(app.toggle_pane_mark(), Some(false));
+        assert!(!app.is_pane_marked("%11"));
+    }
 }
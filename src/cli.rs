@@ -0,0 +1,148 @@
+use anyhow::{bail, Result};
+
+pub const USAGE: &str = "Usage: tmuxui [--filter <text>] [-L <name> | -S <path>] [--tmux <path>]
+
+Options:
+  --filter <text>  Start with the Sessions list filtered to names containing <text>
+  -L <name>        Use the tmux server on socket <name>, like tmux -L
+  -S <path>        Use the tmux server at socket <path>, like tmux -S
+  --tmux <path>    Run this tmux binary instead of the one on PATH
+  -h, --help       Show this help";
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Cli {
+    pub filter: Option<String>,
+    pub socket: Option<String>,
+    pub tmux: Option<String>,
+    pub help: bool,
+}
+
+pub fn parse<I>(args: I) -> Result<Cli>
+where
+    I: IntoIterator<Item = String>,
+{
+    let mut cli = Cli::default();
+    let mut args = args.into_iter();
+
+    while let Some(arg) = args.next() {
+        if let Some(value) = arg.strip_prefix("--filter=") {
+            cli.filter = Some(value.to_string());
+            continue;
+        }
+        if let Some(value) = arg.strip_prefix("--tmux=") {
+            cli.tmux = Some(value.to_string());
+            continue;
+        }
+
+        match arg.as_str() {
+            "--filter" => match args.next() {
+                Some(value) => cli.filter = Some(value),
+                None => bail!("--filter needs a value"),
+            },
+            "--tmux" => match args.next() {
+                Some(value) => cli.tmux = Some(value),
+                None => bail!("--tmux needs a value"),
+            },
+            "-L" | "-S" => {
+                if cli.socket.is_some() {
+                    bail!("use only one of -L or -S");
+                }
+                let Some(value) = args.next().filter(|value| !value.is_empty()) else {
+                    bail!("{arg} needs a value");
+                };
+                if arg == "-L" && value.contains('/') {
+                    bail!("-L takes a socket name; use -S for a path");
+                }
+                cli.socket = Some(if arg == "-S" && !value.contains('/') {
+                    format!("./{value}")
+                } else {
+                    value
+                });
+            }
+            "-h" | "--help" => cli.help = true,
+            _ => bail!("unknown argument `{arg}`"),
+        }
+    }
+
+    cli.filter = cli.filter.filter(|filter| !filter.trim().is_empty());
+    if cli
+        .tmux
+        .as_deref()
+        .is_some_and(|path| path.trim().is_empty())
+    {
+        bail!("--tmux needs a path to the tmux binary");
+    }
+    Ok(cli)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(values: &[&str]) -> Vec<String> {
+        values.iter().map(|value| value.to_string()).collect()
+    }
+
+    #[test]
+    fn parses_filter_in_both_forms() {
+        assert_eq!(
+            parse(args(&["--filter", "dev"])).unwrap().filter.as_deref(),
+            Some("dev")
+        );
+        assert_eq!(
+            parse(args(&["--filter=api"])).unwrap().filter.as_deref(),
+            Some("api")
+        );
+        assert_eq!(parse(args(&["--filter="])).unwrap().filter, None);
+    }
+
+    #[test]
+    fn parses_a_custom_tmux_binary() {
+        assert_eq!(
+            parse(args(&["--tmux", "/opt/tmux/bin/tmux"]))
+                .unwrap()
+                .tmux
+                .as_deref(),
+            Some("/opt/tmux/bin/tmux")
+        );
+        assert_eq!(
+            parse(args(&["--tmux=tmux-next"])).unwrap().tmux.as_deref(),
+            Some("tmux-next")
+        );
+        assert_eq!(parse(args(&[])).unwrap().tmux, None);
+        assert!(parse(args(&["--tmux="])).is_err());
+        assert!(parse(args(&["--tmux"])).is_err());
+    }
+
+    #[test]
+    fn parses_socket_names_and_paths() {
+        assert_eq!(
+            parse(args(&["-L", "work"])).unwrap().socket.as_deref(),
+            Some("work")
+        );
+        assert_eq!(
+            parse(args(&["-S", "/tmp/shared.sock"]))
+                .unwrap()
+                .socket
+                .as_deref(),
+            Some("/tmp/shared.sock")
+        );
+        assert_eq!(
+            parse(args(&["-S", "shared.sock"]))
+                .unwrap()
+                .socket
+                .as_deref(),
+            Some("./shared.sock")
+        );
+        assert!(parse(args(&["-L", "a", "-S", "/tmp/b"])).is_err());
+        assert!(parse(args(&["-L", "/tmp/b"])).is_err());
+        assert!(parse(args(&["-S"])).is_err());
+    }
+
+    #[test]
+    fn rejects_unknown_and_incomplete_arguments() {
+        assert!(parse(args(&["--filter"])).is_err());
+        assert!(parse(args(&["--verbose"])).is_err());
+        assert!(parse(args(&["-h"])).unwrap().help);
+    }
+}
@@ -0,0 +1,300 @@
+use crate::paths;
+use anyhow::{bail, Context, Result};
+use std::{fs, io::ErrorKind, time::Duration};
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum TimeDisplay {
+    #[default]
+    Server,
+    Local,
+    Utc,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SplitDirection {
+    Horizontal,
+    Vertical,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Config {
+    pub time_display: TimeDisplay,
+    pub split_direction: Option<SplitDirection>,
+    pub safe_mode: bool,
+    pub watch_bell: bool,
+    pub confirm_attach: bool,
+    pub inherit_path: bool,
+    pub header_title: Option<String>,
+    pub refresh_interval: Option<Duration>,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            time_display: TimeDisplay::default(),
+            split_direction: None,
+            safe_mode: true,
+            watch_bell: true,
+            confirm_attach: true,
+            inherit_path: true,
+            header_title: None,
+            refresh_interval: Some(Duration::from_secs(2)),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LayoutPreset {
+    pub name: String,
+    pub layout: String,
+}
+
+pub fn load() -> Result<Config> {
+    let Some(path) = paths::config_file() else {
+        return Ok(Config::default());
+    };
+
+    match fs::read_to_string(&path) {
+        Ok(raw) => parse(&raw).with_context(|| format!("invalid config in {}", path.display())),
+        Err(err) if err.kind() == ErrorKind::NotFound => Ok(Config::default()),
+        Err(err) => Err(err).with_context(|| format!("could not read {}", path.display())),
+    }
+}
+
+pub fn parse(raw: &str) -> Result<Config> {
+    let mut config = Config::default();
+
+    for (number, line) in raw.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        let Some((key, value)) = line.split_once('=') else {
+            bail!("line {}: expected `key = value`", number + 1);
+        };
+        let (key, value) = (key.trim(), value.trim());
+
+        match key {
+            "time_display" => {
+                config.time_display = match value {
+                    "server" => TimeDisplay::Server,
+                    "local" => TimeDisplay::Local,
+                    "utc" => TimeDisplay::Utc,
+                    _ => bail!(
+                        "line {}: time_display must be `server`, `local`, or `utc`",
+                        number + 1
+                    ),
+                }
+            }
+            "split_direction" => {
+                config.split_direction = match value {
+                    "ask" => None,
+                    "horizontal" => Some(SplitDirection::Horizontal),
+                    "vertical" => Some(SplitDirection::Vertical),
+                    _ => bail!(
+                        "line {}: split_direction must be `ask`, `horizontal`, or `vertical`",
+                        number + 1
+                    ),
+                }
+            }
+            "safe_mode" => {
+                config.safe_mode = match value {
+                    "on" => true,
+                    "off" => false,
+                    _ => bail!("line {}: safe_mode must be `on` or `off`", number + 1),
+                }
+            }
+            "watch_bell" => {
+                config.watch_bell = match value {
+                    "on" => true,
+                    "off" => false,
+                    _ => bail!("line {}: watch_bell must be `on` or `off`", number + 1),
+                }
+            }
+            "confirm_attach" => {
+                config.confirm_attach = match value {
+                    "on" => true,
+                    "off" => false,
+                    _ => bail!("line {}: confirm_attach must be `on` or `off`", number + 1),
+                }
+            }
+            "inherit_path" => {
+                config.inherit_path = match value {
+                    "on" => true,
+                    "off" => false,
+                    _ => bail!("line {}: inherit_path must be `on` or `off`", number + 1),
+                }
+            }
+            "header_title" => {
+                config.header_title = Some(value.trim_matches('"').to_string());
+            }
+            "refresh_interval" => {
+                config.refresh_interval = match value {
+                    "off" | "0" => None,
+                    _ => match value.parse::<u64>() {
+                        Ok(seconds) => Some(Duration::from_secs(seconds)),
+                        Err(_) => bail!(
+                            "line {}: refresh_interval must be a number of seconds or `off`",
+                            number + 1
+                        ),
+                    },
+                }
+            }
+            _ => bail!("line {}: unknown setting `{key}`", number + 1),
+        }
+    }
+
+    Ok(config)
+}
+
+pub fn load_layout_presets() -> Result<Vec<LayoutPreset>> {
+    let Some(path) = paths::layouts_file() else {
+        return Ok(Vec::new());
+    };
+
+    match fs::read_to_string(&path) {
+        Ok(raw) => parse_layout_presets(&raw)
+            .with_context(|| format!("invalid layout presets in {}", path.display())),
+        Err(err) if err.kind() == ErrorKind::NotFound => Ok(Vec::new()),
+        Err(err) => Err(err).with_context(|| format!("could not read {}", path.display())),
+    }
+}
+
+pub fn save_layout_presets(presets: &[LayoutPreset]) -> Result<()> {
+    let Some(path) = paths::layouts_file() else {
+        bail!("could not find a config directory; set XDG_CONFIG_HOME or HOME");
+    };
+
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir).with_context(|| format!("could not create {}", dir.display()))?;
+    }
+    fs::write(&path, format_layout_presets(presets))
+        .with_context(|| format!("could not write {}", path.display()))
+}
+
+fn parse_layout_presets(raw: &str) -> Result<Vec<LayoutPreset>> {
+    let mut presets = Vec::new();
+
+    for (number, line) in raw.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        let Some((name, layout)) = line.rsplit_once('=') else {
+            bail!("line {}: expected `name = layout`", number + 1);
+        };
+        let (name, layout) = (name.trim(), layout.trim());
+        if name.is_empty() || layout.is_empty() {
+            bail!("line {}: expected `name = layout`", number + 1);
+        }
+
+        presets.push(LayoutPreset {
+            name: name.to_string(),
+            layout: layout.to_string(),
+        });
+    }
+
+    Ok(presets)
+}
+
+fn format_layout_presets(presets: &[LayoutPreset]) -> String {
+    presets
+        .iter()
+        .map(|preset| format!("{} = {}\n", preset.name, preset.layout))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_settings_and_skips_comments() {
+        let config = parse("# times\n\ntime_display = utc\n").expect("config should parse");
+
+        assert_eq!(config.time_display, TimeDisplay::Utc);
+        assert!(config.safe_mode);
+        assert_eq!(config.split_direction, None);
+        assert_eq!(
+            parse("split_direction = vertical")
+                .expect("config should parse")
+                .split_direction,
+            Some(SplitDirection::Vertical)
+        );
+        assert!(
+            !parse("safe_mode = off")
+                .expect("config should parse")
+                .safe_mode
+        );
+        assert!(
+            !parse("watch_bell = off")
+                .expect("config should parse")
+                .watch_bell
+        );
+        assert!(config.confirm_attach);
+        assert!(config.inherit_path);
+        assert!(
+            !parse("inherit_path = off")
+                .expect("config should parse")
+                .inherit_path
+        );
+        assert!(
+            !parse("confirm_attach = off")
+                .expect("config should parse")
+                .confirm_attach
+        );
+        assert_eq!(config.refresh_interval, Some(Duration::from_secs(2)));
+        assert_eq!(
+            parse("refresh_interval = 5")
+                .expect("config should parse")
+                .refresh_interval,
+            Some(Duration::from_secs(5))
+        );
+        assert_eq!(
+            parse("refresh_interval = off")
+                .expect("config should parse")
+                .refresh_interval,
+            None
+        );
+        assert_eq!(
+            parse("header_title = \"prod {host}\"")
+                .expect("config should parse")
+                .header_title
+                .as_deref(),
+            Some("prod {host}")
+        );
+    }
+
+    #[test]
+    fn rejects_unknown_settings_and_values() {
+        assert!(parse("time_display = mars").is_err());
+        assert!(parse("colour = blue").is_err());
+        assert!(parse("time_display").is_err());
+        assert!(parse("safe_mode = maybe").is_err());
+        assert!(parse("refresh_interval = 1.5").is_err());
+    }
+
+    #[test]
+    fn layout_presets_round_trip() {
+        let presets = vec![
+            LayoutPreset {
+                name: "editor + logs".to_string(),
+                layout: "489f,100x40,0,0{50x40,0,0,0,49x40,51,0,1}".to_string(),
+            },
+            LayoutPreset {
+                name: "a=b".to_string(),
+                layout: "c1d2,80x24,0,0,3".to_string(),
+            },
+        ];
+
+        let raw = format_layout_presets(&presets);
+
+        assert_eq!(
+            parse_layout_presets(&raw).expect("presets should parse"),
+            presets
+        );
+        assert!(parse_layout_presets("just a name").is_err());
+    }
+}
@@ -1,29 +1,84 @@
+mod ansi;
 mod app;
+mod cli;
+mod config;
 mod models;
+mod paths;
 mod tmux;
 mod ui;
+mod util;
 
 use anyhow::{bail, Context, Result};
-use app::{App, ConfirmIntent, FocusArea, InputIntent, ModalState};
+use app::{
+    App, ConfirmIntent, FocusArea, InputIntent, InputModalState, ModalState, PagerState,
+    PendingPipe, PickerIntent,
+};
+use config::{LayoutPreset, SplitDirection};
 use crossterm::{
-    event::{self, Event, KeyCode, KeyEventKind, KeyModifiers},
+    event::{
+        self, DisableMouseCapture, EnableMouseCapture, Event, KeyCode, KeyEventKind, KeyModifiers,
+        MouseButton, MouseEventKind,
+    },
     execute,
-    terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
+    terminal::{
+        self, disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen,
+    },
 };
 use ratatui::{backend::CrosstermBackend, prelude::Backend, Terminal};
-use std::{env, io::Stdout, process::Command, time::Duration};
+use std::{
+    env,
+    io::{ErrorKind, Stdout, Write},
+    path::PathBuf,
+    process::{Command, Stdio},
+    thread,
+    time::{Duration, Instant, SystemTime, UNIX_EPOCH},
+};
 
 #[cfg(unix)]
 use std::os::unix::process::CommandExt;
 
+const WATCH_POLL_INTERVAL: Duration = Duration::from_secs(2);
+const PREVIEW_POLL_INTERVAL: Duration = Duration::from_secs(1);
+const DOUBLE_CLICK_INTERVAL: Duration = Duration::from_millis(400);
+const PANE_RESIZE_STEP: u16 = 5;
+
 fn main() -> Result<()> {
+    let cli = cli::parse(env::args().skip(1))?;
+    if cli.help {
+        println!("{}", cli::USAGE);
+        return Ok(());
+    }
+
+    tmux::set_binary(cli.tmux.clone());
+    let stale_nesting = tmux::stale_tmux_env();
+    if stale_nesting.is_some() {
+        env::remove_var("TMUX");
+    }
+    tmux::set_socket(cli.socket.clone());
+
     let mut app = App::new();
+    app.socket = cli.socket;
+    if let Some(detail) = stale_nesting {
+        app.set_warning_banner(
+            "Stale TMUX variable",
+            format!("{detail} switch-client would fail, so Enter attaches directly instead."),
+        );
+    }
+    if let Some(filter) = cli.filter.as_deref() {
+        app.start_filtered(filter);
+    }
     let run_result = {
         let mut terminal_session = TerminalSession::enter()?;
         run_loop(terminal_session.terminal(), &mut app)
     };
 
     run_result?;
+    if app.detach_and_quit {
+        return Ok(tmux::detach_client()?);
+    }
+    if let Some(path) = app.target_shell.as_deref() {
+        return open_shell(path);
+    }
     handle_attach(&app)
 }
 
@@ -35,7 +90,7 @@ impl TerminalSession {
     fn enter() -> Result<Self> {
         enable_raw_mode()?;
         let mut stdout = std::io::stdout();
-        execute!(stdout, EnterAlternateScreen)?;
+        execute!(stdout, EnterAlternateScreen, EnableMouseCapture)?;
         let backend = CrosstermBackend::new(stdout);
         let terminal = Terminal::new(backend)?;
 
@@ -50,18 +105,32 @@ impl TerminalSession {
 impl Drop for TerminalSession {
     fn drop(&mut self) {
         let _ = disable_raw_mode();
-        let _ = execute!(self.terminal.backend_mut(), LeaveAlternateScreen);
+        let _ = execute!(
+            self.terminal.backend_mut(),
+            DisableMouseCapture,
+            LeaveAlternateScreen
+        );
         let _ = self.terminal.show_cursor();
     }
 }
 
 fn run_loop<B: Backend>(terminal: &mut Terminal<B>, app: &mut App) -> Result<()> {
+    let mut last_watch_poll = Instant::now();
+    let mut last_preview_poll = Instant::now();
+    let mut last_auto_refresh = Instant::now();
+    let mut last_click: Option<(Instant, FocusArea, usize)> = None;
     loop {
-        terminal.draw(|frame| ui::draw(frame, app))?;
+        if app.needs_redraw {
+            terminal.draw(|frame| ui::draw(frame, app))?;
+            app.needs_redraw = false;
+        }
 
         if event::poll(Duration::from_millis(100))? {
-            if let Event::Key(key) = event::read()? {
-                if key.kind == KeyEventKind::Press {
+            match event::read()? {
+                Event::Key(key) if key.kind == KeyEventKind::Press => {
+                    if !is_list_navigation(app, key.code) {
+                        app.flush_pending_refresh();
+                    }
                     if app.help.visible {
                         handle_help_mode(app, key.code, key.modifiers);
                     } else if app.filter.active {
@@ -72,10 +141,75 @@ fn run_loop<B: Backend>(terminal: &mut Terminal<B>, app: &mut App) -> Result<()>
                             ModalState::Confirm(_) => {
                                 handle_confirm_mode(app, key.code, key.modifiers)
                             }
+                            ModalState::Picker(_) => {
+                                handle_picker_mode(app, key.code, key.modifiers)
+                            }
+                            ModalState::Pager(_) => handle_pager_mode(app, key.code, key.modifiers),
+                            ModalState::Split(_) => handle_split_mode(app, key.code, key.modifiers),
                             ModalState::None => handle_normal_mode(app, key.code, key.modifiers),
                         }
                     }
+                    app.needs_redraw = true;
+                }
+                Event::Mouse(mouse) if is_idle(app) => {
+                    if let MouseEventKind::ScrollDown | MouseEventKind::ScrollUp = mouse.kind {
+                        let down = mouse.kind == MouseEventKind::ScrollDown;
+                        if app.scroll_list(mouse.column, mouse.row, down) {
+                            app.needs_redraw = true;
+                        }
+                    } else if let MouseEventKind::Down(MouseButton::Left) = mouse.kind {
+                        let clicked = app.click_list(mouse.column, mouse.row);
+                        let double = clicked.is_some()
+                            && matches!(
+                                last_click,
+                                Some((at, focus, index))
+                                    if at.elapsed() <= DOUBLE_CLICK_INTERVAL
+                                        && Some((focus, index)) == clicked
+                            );
+                        if double {
+                            last_click = None;
+                            app.flush_pending_refresh();
+                            handle_attach_action(app);
+                        } else {
+                            last_click =
+                                clicked.map(|(focus, index)| (Instant::now(), focus, index));
+                        }
+                        app.needs_redraw = true;
+                    }
+                }
+                Event::Resize(width, height) => {
+                    app.note_terminal_resize(width, height);
+                    app.needs_redraw = true;
                 }
+                _ => {}
+            }
+        }
+
+        if let Some(pipe) = app.pending_pipe.take() {
+            run_pipe(terminal, app, pipe)?;
+        }
+
+        if app.has_pending_refresh() && !event::poll(Duration::ZERO)? {
+            app.flush_pending_refresh();
+        }
+
+        if !app.watched_panes.is_empty() && last_watch_poll.elapsed() >= WATCH_POLL_INTERVAL {
+            poll_watched_panes(app);
+            last_watch_poll = Instant::now();
+        }
+
+        if app.preview.is_some()
+            && app.modal == ModalState::None
+            && last_preview_poll.elapsed() >= PREVIEW_POLL_INTERVAL
+        {
+            app.refresh_preview();
+            last_preview_poll = Instant::now();
+        }
+
+        if let Some(interval) = app.config.refresh_interval {
+            if app.modal == ModalState::None && last_auto_refresh.elapsed() >= interval {
+                app.refresh_quietly();
+                last_auto_refresh = Instant::now();
             }
         }
 
@@ -85,10 +219,98 @@ fn run_loop<B: Backend>(terminal: &mut Terminal<B>, app: &mut App) -> Result<()>
     }
 }
 
+fn run_pipe<B: Backend>(
+    terminal: &mut Terminal<B>,
+    app: &mut App,
+    pipe: PendingPipe,
+) -> Result<()> {
+    disable_raw_mode()?;
+    execute!(std::io::stdout(), DisableMouseCapture, LeaveAlternateScreen)?;
+    terminal.show_cursor()?;
+
+    let result = pipe_to_command(&pipe);
+    print!("\n[tmuxui] Press Enter to return.");
+    let _ = std::io::stdout().flush();
+    let _ = std::io::stdin().read_line(&mut String::new());
+
+    enable_raw_mode()?;
+    execute!(std::io::stdout(), EnterAlternateScreen, EnableMouseCapture)?;
+    terminal.clear()?;
+    app.needs_redraw = true;
+
+    match result {
+        Ok(status) if status.success() => app.set_success_banner(
+            "Pipe finished",
+            format!(
+                "`{}` read {}'s scrollback and exited cleanly.",
+                pipe.command, pipe.pane_id
+            ),
+        ),
+        Ok(status) => app.set_warning_banner(
+            "Pipe finished",
+            format!("`{}` exited with {status}.", pipe.command),
+        ),
+        Err(err) => app.set_error_banner(
+            "Could not run command",
+            format_user_error("Pipe failed", err),
+        ),
+    }
+    Ok(())
+}
+
+fn pipe_to_command(pipe: &PendingPipe) -> Result<std::process::ExitStatus> {
+    let mut child = Command::new("sh")
+        .args(["-c", &pipe.command])
+        .stdin(Stdio::piped())
+        .spawn()
+        .with_context(|| format!("could not start `{}`", pipe.command))?;
+
+    let mut stdin = child
+        .stdin
+        .take()
+        .context("could not open the command's input")?;
+    let content = pipe.content.clone();
+    let writer = thread::spawn(move || match stdin.write_all(content.as_bytes()) {
+        Err(err) if err.kind() != ErrorKind::BrokenPipe => Err(err),
+        _ => Ok(()),
+    });
+
+    let status = child
+        .wait()
+        .with_context(|| format!("could not wait for `{}`", pipe.command))?;
+    if let Ok(Err(err)) = writer.join() {
+        return Err(err).context("could not send the scrollback to the command");
+    }
+    Ok(status)
+}
+
+fn is_idle(app: &App) -> bool {
+    !app.help.visible && !app.filter.active && app.modal == ModalState::None
+}
+
+fn is_list_navigation(app: &App, code: KeyCode) -> bool {
+    is_idle(app)
+        && matches!(
+            code,
+            KeyCode::Down
+                | KeyCode::Up
+                | KeyCode::Home
+                | KeyCode::End
+                | KeyCode::Char('j')
+                | KeyCode::Char('k')
+                | KeyCode::Char('g')
+                | KeyCode::Char('G')
+        )
+}
+
 fn handle_help_mode(app: &mut App, code: KeyCode, modifiers: KeyModifiers) {
     match code {
         KeyCode::Char('c') if modifiers.contains(KeyModifiers::CONTROL) => app.should_quit = true,
         KeyCode::Esc | KeyCode::Char('q') | KeyCode::Char('?') => app.help.visible = false,
+        KeyCode::Char('b') => {
+            app.help.visible = false;
+            handle_key_bindings_action(app);
+        }
         _ => {}
     }
 }
@@ -97,6 +319,7 @@ fn handle_normal_mode(app: &mut App, code: KeyCode, modifiers: KeyModifiers) {
     match code {
         KeyCode::Char('c') if modifiers.contains(KeyModifiers::CONTROL) => app.should_quit = true,
         KeyCode::Char('q') | KeyCode::Esc => app.should_quit = true,
+        KeyCode::Char('Q') => handle_detach_and_quit_action(app),
         KeyCode::Char('?') => app.help.visible = true,
         KeyCode::Char('/') => app.open_filter(),
         KeyCode::Char('r') => {
@@ -105,15 +328,159 @@ fn handle_normal_mode(app: &mut App, code: KeyCode, modifiers: KeyModifiers) {
                 app.set_info_banner("Refreshed", "tmux data is up to date.");
             }
         }
+        KeyCode::Char('k') if modifiers.contains(KeyModifiers::CONTROL) => {
+            handle_kill_server_action(app)
+        }
         KeyCode::Down | KeyCode::Char('j') => app.nav_down(),
         KeyCode::Up | KeyCode::Char('k') => app.nav_up(),
         KeyCode::Left | KeyCode::Char('h') | KeyCode::BackTab => app.cycle_focus_back(),
         KeyCode::Right | KeyCode::Char('l') | KeyCode::Tab => app.cycle_focus(),
         KeyCode::Home | KeyCode::Char('g') => app.nav_first(),
         KeyCode::End | KeyCode::Char('G') => app.nav_last(),
+        KeyCode::Char('d') if modifiers.contains(KeyModifiers::CONTROL) => app.nav_half_page(true),
+        KeyCode::Char('u') if modifiers.contains(KeyModifiers::CONTROL) => app.nav_half_page(false),
+        KeyCode::Char('H') => {
+            app.reset_view();
+            if app.connection == tmux::TmuxConnectionState::Connected {
+                app.set_info_banner("Back to start", "Focus is on the first session again.");
+            }
+        }
         KeyCode::Char('n') => handle_new_action(app),
+        KeyCode::Char('N') => handle_dev_session_action(app),
         KeyCode::Char('R') => handle_rename_action(app),
         KeyCode::Char('d') => handle_delete_action(app),
+        KeyCode::Char('P') => handle_move_pane_action(app),
+        KeyCode::Char('o') if modifiers.contains(KeyModifiers::CONTROL) => {
+            handle_rotate_window_action(app)
+        }
+        KeyCode::Char('o') if app.focus == FocusArea::Sessions => {
+            app.cycle_session_sort();
+            app.set_info_banner(
+                "Sessions sorted",
+                format!(
+                    "Sorted by {}. Press o again to switch.",
+                    app.session_sort.label()
+                ),
+            );
+        }
+        KeyCode::Char('o') => handle_next_active_pane_action(app),
+        KeyCode::Char('e') if app.focus == FocusArea::Sessions => handle_session_hook_action(app),
+        KeyCode::Char('e') => handle_export_command_action(app),
+        KeyCode::Char('c') => handle_copy_path_action(app),
+        KeyCode::Char('v') => handle_scrollback_action(app),
+        KeyCode::Char('Y') if app.focus == FocusArea::Sessions => handle_copy_tree_action(app),
+        KeyCode::Char('Y') => handle_copy_scrollback_action(app),
+        KeyCode::Char('M') if app.focus == FocusArea::Sessions => handle_merge_sessions_action(app),
+        KeyCode::Char('M') if app.focus == FocusArea::Windows => {
+            handle_move_window_to_session_action(app)
+        }
+        KeyCode::Char('M') => handle_make_main_action(app),
+        KeyCode::Char('U') => handle_recently_killed_action(app),
+        KeyCode::Char('X') => handle_clients_action(app),
+        KeyCode::Char('B') => handle_buffers_action(app),
+        KeyCode::Char('S') => handle_sockets_action(app),
+        KeyCode::Char('C') => handle_clone_action(app),
+        KeyCode::Char('D') if app.focus == FocusArea::Sessions => {
+            handle_destroy_unattached_action(app)
+        }
+        KeyCode::Char('D') => handle_duplicate_window_action(app),
+        KeyCode::Char('t') => handle_tile_action(app),
+        KeyCode::Char('=') => handle_equalize_action(app),
+        KeyCode::Char('z') => handle_zoom_pane_action(app),
+        KeyCode::Char('<') => handle_resize_pane_action(app, tmux::ResizeDirection::Left),
+        KeyCode::Char('>') => handle_resize_pane_action(app, tmux::ResizeDirection::Right),
+        KeyCode::Char('-') => handle_resize_pane_action(app, tmux::ResizeDirection::Up),
+        KeyCode::Char('+') => handle_resize_pane_action(app, tmux::ResizeDirection::Down),
+        KeyCode::Char('w') if app.focus == FocusArea::Panes => handle_watch_pane_action(app),
+        KeyCode::Char('w') => handle_save_layout_action(app),
+        KeyCode::Char('L') => handle_layout_presets_action(app),
+        KeyCode::Char('i') => {
+            app.compact_inactive_panes = !app.compact_inactive_panes;
+            if app.compact_inactive_panes {
+                app.set_info_banner(
+                    "Compact panes",
+                    "Inactive panes now show only their command.",
+                );
+            } else {
+                app.set_info_banner("Full panes", "Every pane shows its size and path again.");
+            }
+        }
+        KeyCode::Char('A') if app.focus == FocusArea::Windows => {
+            handle_aggressive_resize_action(app)
+        }
+        KeyCode::Char('A') => {
+            app.cycle_session_state_filter();
+            app.set_info_banner(
+                "Session filter",
+                format!(
+                    "Showing {} sessions. Press A again to switch.",
+                    app.session_state_filter.label()
+                ),
+            );
+        }
+        KeyCode::Char('I') => {
+            app.show_ids = !app.show_ids;
+            if app.show_ids {
+                app.set_info_banner(
+                    "Showing ids",
+                    "Lists lead with tmux ids like $1, @2, and %3.",
+                );
+            } else {
+                app.set_info_banner(
+                    "Showing names",
+                    "Lists lead with session and window names again.",
+                );
+            }
+        }
+        KeyCode::Char('!') => handle_shell_action(app),
+        KeyCode::Char('#') => handle_display_panes_action(app),
+        KeyCode::Char('O') => handle_toggle_mouse_action(app),
+        KeyCode::Char('y') => handle_synchronize_panes_action(app),
+        KeyCode::Char('T') => handle_status_position_action(app),
+        KeyCode::Char('s') => handle_send_keys_action(app),
+        KeyCode::Char('|') => handle_pipe_action(app),
+        KeyCode::Char('\'') => handle_resume_action(app),
+        KeyCode::Char('W') => handle_windows_from_directory_action(app),
+        KeyCode::Char('p') => {
+            if app.toggle_preview() {
+                app.set_info_banner(
+                    "Preview on",
+                    "The panel below the lists shows the selected pane's screen. Press p to hide it.",
+                );
+            } else {
+                app.set_info_banner("Preview off", "The selection details are back.");
+            }
+        }
+        KeyCode::Char('f') => {
+            let query = app
+                .command_search
+                .as_ref()
+                .map(|search| search.query.clone())
+                .unwrap_or_default();
+            app.open_input_modal(InputIntent::FindCommand, query);
+        }
+        KeyCode::Char('F') => match app
+            .command_search
+            .as_ref()
+            .map(|search| search.query.clone())
+        {
+            Some(query) => find_pane_by_command(app, &query),
+            None => app.open_input_modal(InputIntent::FindCommand, ""),
+        },
+        KeyCode::Char('K') => handle_move_window_action(app, false),
+        KeyCode::Char('J') if app.focus == FocusArea::Panes => handle_join_pane_action(app),
+        KeyCode::Char('J') => handle_move_window_action(app, true),
+        KeyCode::Char(' ') if app.focus == FocusArea::Windows => handle_cycle_layout_action(app),
+        KeyCode::Char(' ') => handle_mark_pane_action(app),
+        KeyCode::Char('V') => handle_builtin_layouts_action(app),
+        KeyCode::Char('x') => handle_swap_marked_action(app),
+        KeyCode::Char('a') => handle_attach_command_action(app),
+        KeyCode::Char('m') => handle_action_menu_action(app),
+        KeyCode::Char('b') if app.focus == FocusArea::Panes => handle_break_pane_action(app),
+        KeyCode::Char('b') => handle_branch_rename_action(app),
+        KeyCode::Backspace => handle_last_window_action(app),
+        KeyCode::Char(']') => handle_step_window_action(app, true),
+        KeyCode::Char('[') => handle_step_window_action(app, false),
         KeyCode::Enter => handle_attach_action(app),
         _ => {}
     }
@@ -123,7 +490,14 @@ fn handle_filter_mode(app: &mut App, code: KeyCode, modifiers: KeyModifiers) {
     match code {
         KeyCode::Char('c') if modifiers.contains(KeyModifiers::CONTROL) => app.should_quit = true,
         KeyCode::Char('?') => app.help.visible = true,
-        KeyCode::Enter => app.close_filter(),
+        KeyCode::Enter => {
+            app.close_filter();
+            app.flush_pending_refresh();
+            handle_attach_action(app);
+        }
+        KeyCode::Tab => app.close_filter(),
+        KeyCode::Down => app.nav_down(),
+        KeyCode::Up => app.nav_up(),
         KeyCode::Esc => {
             app.clear_filter();
             app.set_info_banner("Filter cleared", "Showing every matching item again.");
@@ -143,42 +517,202 @@ fn handle_input_mode(app: &mut App, code: KeyCode, modifiers: KeyModifiers) {
         KeyCode::Enter => handle_input_submission(app),
         KeyCode::Esc => {
             app.close_modal();
+            app.pending_session = None;
             app.set_info_banner("Cancelled", "No changes were made.");
         }
-        KeyCode::Backspace => {
+        KeyCode::Backspace => edit_input(app, InputModalState::backspace),
+        KeyCode::Delete => edit_input(app, InputModalState::delete),
+        KeyCode::Char('u') if modifiers.contains(KeyModifiers::CONTROL) => {
+            edit_input(app, InputModalState::clear)
+        }
+        KeyCode::Char(character) if modifiers.is_empty() || modifiers == KeyModifiers::SHIFT => {
+            edit_input(app, |modal| modal.insert(character))
+        }
+        KeyCode::Left => {
             if let Some(modal) = app.input_modal_mut() {
-                modal.value.pop();
-                modal.error = None;
+                modal.move_left();
             }
         }
-        KeyCode::Char('u') if modifiers.contains(KeyModifiers::CONTROL) => {
+        KeyCode::Right => {
             if let Some(modal) = app.input_modal_mut() {
-                modal.value.clear();
-                modal.error = None;
+                modal.move_right();
             }
         }
-        KeyCode::Char(character) if modifiers.is_empty() || modifiers == KeyModifiers::SHIFT => {
+        KeyCode::Home => {
+            if let Some(modal) = app.input_modal_mut() {
+                modal.move_home();
+            }
+        }
+        KeyCode::End => {
             if let Some(modal) = app.input_modal_mut() {
-                modal.value.push(character);
-                modal.error = None;
+                modal.move_end();
             }
         }
         _ => {}
     }
 }
 
+fn edit_input(app: &mut App, edit: impl FnOnce(&mut InputModalState)) {
+    if let Some(modal) = app.input_modal_mut() {
+        edit(modal);
+        modal.clear_feedback();
+    }
+}
+
 fn handle_confirm_mode(app: &mut App, code: KeyCode, modifiers: KeyModifiers) {
     match code {
         KeyCode::Char('c') if modifiers.contains(KeyModifiers::CONTROL) => app.should_quit = true,
         KeyCode::Char('y') | KeyCode::Enter => handle_confirmation(app),
+        KeyCode::Down | KeyCode::Char('j') => {
+            if let Some(modal) = app.confirm_modal_mut() {
+                modal.scroll_down();
+            }
+        }
+        KeyCode::Up | KeyCode::Char('k') => {
+            if let Some(modal) = app.confirm_modal_mut() {
+                modal.scroll_up();
+            }
+        }
         KeyCode::Char('n') | KeyCode::Esc => {
             app.close_modal();
+            app.attach_command = None;
             app.set_info_banner("Cancelled", "No changes were made.");
         }
         _ => {}
     }
 }
 
+fn handle_picker_mode(app: &mut App, code: KeyCode, modifiers: KeyModifiers) {
+    match code {
+        KeyCode::Char('c') if modifiers.contains(KeyModifiers::CONTROL) => app.should_quit = true,
+        KeyCode::Enter => handle_picker_selection(app),
+        KeyCode::Esc | KeyCode::Char('q') => {
+            app.close_modal();
+            app.set_info_banner("Cancelled", "No changes were made.");
+        }
+        KeyCode::Down | KeyCode::Char('j') => app.picker_nav_down(),
+        KeyCode::Up | KeyCode::Char('k') => app.picker_nav_up(),
+        KeyCode::Home | KeyCode::Char('g') => app.picker_nav_first(),
+        KeyCode::End | KeyCode::Char('G') => app.picker_nav_last(),
+        KeyCode::Char('d') => match app.picker_modal().map(|modal| modal.intent) {
+            Some(PickerIntent::Buffers) => handle_delete_buffer_action(app),
+            Some(PickerIntent::LayoutPresets) => handle_delete_layout_preset_action(app),
+            _ => {}
+        },
+        _ => {}
+    }
+}
+
+fn handle_pager_mode(app: &mut App, code: KeyCode, modifiers: KeyModifiers) {
+    if code == KeyCode::Char('c') && modifiers.contains(KeyModifiers::CONTROL) {
+        app.should_quit = true;
+        return;
+    }
+
+    let Some(pager) = app.pager_mut() else {
+        return;
+    };
+
+    if pager.searching {
+        match code {
+            KeyCode::Enter => pager.finish_search(),
+            KeyCode::Esc => pager.clear_query(),
+            KeyCode::Backspace => pager.pop_query_char(),
+            KeyCode::Char('u') if modifiers.contains(KeyModifiers::CONTROL) => pager.clear_query(),
+            KeyCode::Char(character)
+                if modifiers.is_empty() || modifiers == KeyModifiers::SHIFT =>
+            {
+                pager.push_query_char(character);
+            }
+            _ => {}
+        }
+        return;
+    }
+
+    if !pager.preview_focused {
+        match code {
+            KeyCode::Tab | KeyCode::BackTab => pager.toggle_focus(),
+            KeyCode::Esc | KeyCode::Char('q') => app.close_modal(),
+            KeyCode::Down | KeyCode::Char('j') => switch_pager_pane(app, App::nav_down),
+            KeyCode::Up | KeyCode::Char('k') => switch_pager_pane(app, App::nav_up),
+            KeyCode::Home | KeyCode::Char('g') => switch_pager_pane(app, App::nav_first),
+            KeyCode::End | KeyCode::Char('G') => switch_pager_pane(app, App::nav_last),
+            _ => {}
+        }
+        return;
+    }
+
+    match code {
+        KeyCode::Esc | KeyCode::Char('q') => app.close_modal(),
+        KeyCode::Tab | KeyCode::BackTab => pager.toggle_focus(),
+        KeyCode::Char('e') => toggle_pager_colors(app),
+        KeyCode::Char('/') => pager.start_search(),
+        KeyCode::Down | KeyCode::Char('j') => pager.scroll_down(1),
+        KeyCode::Up | KeyCode::Char('k') => pager.scroll_up(1),
+        KeyCode::PageDown => pager.scroll_down(pager.viewport_height.max(1)),
+        KeyCode::PageUp => pager.scroll_up(pager.viewport_height.max(1)),
+        KeyCode::Char('d') if modifiers.contains(KeyModifiers::CONTROL) => {
+            pager.scroll_down(pager.page_size())
+        }
+        KeyCode::Char('u') if modifiers.contains(KeyModifiers::CONTROL) => {
+            pager.scroll_up(pager.page_size())
+        }
+        KeyCode::Home | KeyCode::Char('g') => pager.scroll_to_top(),
+        KeyCode::End | KeyCode::Char('G') => pager.scroll_to_bottom(),
+        _ => {}
+    }
+}
+
+fn toggle_pager_colors(app: &mut App) {
+    let Some((pane_id, ansi)) = app
+        .pager_mut()
+        .and_then(|pager| Some((pager.pane_id.clone()?, !pager.ansi)))
+    else {
+        return;
+    };
+
+    match tmux::capture_pane_history(&pane_id, ansi) {
+        Ok(content) => {
+            if let Some(pager) = app.pager_mut() {
+                pager.ansi = ansi;
+                pager.show_pane(pane_id, &content);
+            }
+        }
+        Err(err) => app.set_error_banner(
+            "Could not capture pane",
+            format_user_error("Capture failed", err),
+        ),
+    }
+}
+
+fn switch_pager_pane(app: &mut App, navigate: fn(&mut App)) {
+    app.focus = FocusArea::Panes;
+    navigate(app);
+
+    let Some(pane_id) = app.get_selected_pane().map(|pane| pane.id.clone()) else {
+        return;
+    };
+    if app
+        .pager_mut()
+        .is_some_and(|pager| pager.pane_id.as_deref() == Some(pane_id.as_str()))
+    {
+        return;
+    }
+
+    let ansi = app.pager_mut().is_some_and(|pager| pager.ansi);
+    match tmux::capture_pane_history(&pane_id, ansi) {
+        Ok(content) => {
+            if let Some(pager) = app.pager_mut() {
+                pager.show_pane(pane_id, &content);
+            }
+        }
+        Err(err) => app.set_error_banner(
+            "Could not capture pane",
+            format_user_error("Capture failed", err),
+        ),
+    }
+}
+
 fn handle_new_action(app: &mut App) {
     match app.focus {
         FocusArea::Sessions => app.open_input_modal(InputIntent::NewSession, ""),
@@ -193,22 +727,8 @@ fn handle_new_action(app: &mut App) {
             }
         }
         FocusArea::Panes => {
-            let pane_id = app.get_selected_pane().map(|pane| pane.id.clone());
-            if let Some(pane_id) = pane_id {
-                match tmux::create_pane(&pane_id) {
-                    Ok(()) => {
-                        app.refresh_all();
-                        app.focus = FocusArea::Panes;
-                        app.set_success_banner(
-                            "Pane split",
-                            "The pane was split. Use Enter to attach or Tab to review the layout.",
-                        );
-                    }
-                    Err(err) => app.set_error_banner(
-                        "Could not split pane",
-                        format_user_error("Split failed", err),
-                    ),
-                }
+            if let Some(pane) = app.get_selected_pane().cloned() {
+                app.open_split_modal(&pane);
             } else {
                 app.set_info_banner("Pane unavailable", "Select a pane before splitting it.");
             }
@@ -216,6 +736,93 @@ fn handle_new_action(app: &mut App) {
     }
 }
 
+fn handle_dev_session_action(app: &mut App) {
+    if app.focus != FocusArea::Sessions {
+        app.set_info_banner(
+            "Dev session unavailable",
+            "Focus the Sessions list to create a dev session.",
+        );
+        return;
+    }
+
+    app.open_input_modal(InputIntent::DevSession, "");
+}
+
+fn handle_split_mode(app: &mut App, code: KeyCode, modifiers: KeyModifiers) {
+    match code {
+        KeyCode::Char('c') if modifiers.contains(KeyModifiers::CONTROL) => app.should_quit = true,
+        KeyCode::Enter => handle_split_submission(app),
+        KeyCode::Esc | KeyCode::Char('q') => {
+            app.close_modal();
+            app.set_info_banner("Cancelled", "No changes were made.");
+        }
+        KeyCode::Char('h') => {
+            if let Some(modal) = app.split_modal_mut() {
+                modal.choose(SplitDirection::Horizontal);
+            }
+        }
+        KeyCode::Char('v') => {
+            if let Some(modal) = app.split_modal_mut() {
+                modal.choose(SplitDirection::Vertical);
+            }
+        }
+        KeyCode::Up | KeyCode::Right | KeyCode::Char('k') => {
+            if let Some(modal) = app.split_modal_mut() {
+                modal.grow();
+            }
+        }
+        KeyCode::Down | KeyCode::Left | KeyCode::Char('j') => {
+            if let Some(modal) = app.split_modal_mut() {
+                modal.shrink();
+            }
+        }
+        _ => {}
+    }
+}
+
+fn handle_split_submission(app: &mut App) {
+    let Some(modal) = app.split_modal_mut().cloned() else {
+        return;
+    };
+
+    let Some(direction) = modal.direction else {
+        app.set_modal_error("Press h to split side by side or v to split below first.");
+        return;
+    };
+
+    let start_dir = app
+        .config
+        .inherit_path
+        .then(|| {
+            app.panes
+                .iter()
+                .find(|pane| pane.id == modal.pane_id)
+                .map(|pane| pane.current_path.clone())
+        })
+        .flatten()
+        .filter(|path| !path.is_empty());
+    match tmux::create_pane(
+        &modal.pane_id,
+        direction,
+        modal.percent,
+        start_dir.as_deref(),
+    ) {
+        Ok(()) => {
+            app.close_modal();
+            app.refresh_all();
+            app.focus = FocusArea::Panes;
+            app.set_success_banner(
+                "Pane split",
+                format!(
+                    "The new pane takes {}% of `{}`. Use Enter to attach or Tab to review the layout.",
+                    modal.percent, modal.pane_id
+                ),
+            );
+        }
+        Err(err) => app.set_modal_error(format_user_error("Split failed", err)),
+    }
+}
+
 fn handle_rename_action(app: &mut App) {
     match app.focus {
         FocusArea::Sessions => {
@@ -236,10 +843,16 @@ fn handle_rename_action(app: &mut App) {
                 app.set_info_banner("Rename unavailable", "Select a window before renaming it.");
             }
         }
-        FocusArea::Panes => app.set_info_banner(
-            "Rename unavailable",
-            "Pane names are managed inside tmux itself.",
-        ),
+        FocusArea::Panes => {
+            let title = app
+                .get_selected_pane()
+                .map(|pane| app.pane_title(pane).unwrap_or_default().to_string());
+            if let Some(title) = title {
+                app.open_input_modal(InputIntent::PaneTitle, title);
+            } else {
+                app.set_info_banner("Title unavailable", "Select a pane before titling it.");
+            }
+        }
     }
 }
 
@@ -269,105 +882,1990 @@ fn handle_delete_action(app: &mut App) {
     }
 }
 
-fn handle_attach_action(app: &mut App) {
-    match app.focus {
-        FocusArea::Sessions => {
-            let target = app
-                .get_selected_session()
-                .map(|session| session.name.clone());
-            if let Some(target) = target {
-                app.target_attach = Some(target);
-                app.should_quit = true;
-            } else {
-                app.set_info_banner("Attach unavailable", "Select a session to attach.");
-            }
+fn handle_move_pane_action(app: &mut App) {
+    if app.focus != FocusArea::Panes {
+        app.set_info_banner(
+            "Move unavailable",
+            "Focus the Panes list to move a pane to another position.",
+        );
+        return;
+    }
+
+    if app.get_selected_pane().is_none() {
+        app.set_info_banner("Move unavailable", "Select a pane before moving it.");
+        return;
+    }
+
+    if app.panes.len() < 2 {
+        app.set_info_banner(
+            "Move unavailable",
+            "This window only has one pane, so there is nowhere to move it.",
+        );
+        return;
+    }
+
+    let items = app.pane_position_items();
+    let selected = app.selected_pane_position();
+    app.open_picker_modal(PickerIntent::PanePosition, items, selected);
+}
+
+fn handle_make_main_action(app: &mut App) {
+    if app.focus != FocusArea::Panes {
+        app.set_info_banner(
+            "Main pane unavailable",
+            "Focus the Panes list to choose a main pane.",
+        );
+        return;
+    }
+
+    let pane_id = app.get_selected_pane().map(|pane| pane.id.clone());
+    let window_id = app.get_selected_window().map(|window| window.id.clone());
+    let (Some(pane_id), Some(window_id)) = (pane_id, window_id) else {
+        app.set_info_banner(
+            "Main pane unavailable",
+            "Select a pane before promoting it.",
+        );
+        return;
+    };
+
+    match tmux::make_main(&pane_id, &window_id) {
+        Ok(()) => {
+            app.refresh_all();
+            app.focus = FocusArea::Panes;
+            app.set_success_banner(
+                "Main pane set",
+                format!("`{pane_id}` now leads a main-vertical layout."),
+            );
         }
-        FocusArea::Windows => {
-            let session_name = app
-                .get_selected_session()
-                .map(|session| session.name.clone());
-            let window_id = app.get_selected_window().map(|window| window.id.clone());
+        Err(err) => app.set_error_banner(
+            "Could not promote pane",
+            format_user_error("Layout failed", err),
+        ),
+    }
+}
 
-            match (session_name, window_id) {
-                (Some(session_name), Some(window_id)) => {
-                    if let Err(err) = tmux::select_window(&window_id) {
-                        app.set_error_banner(
-                            "Could not select window",
-                            format_user_error("Attach failed", err),
-                        );
-                        return;
-                    }
+fn handle_clone_action(app: &mut App) {
+    if app.focus == FocusArea::Sessions {
+        let Some(name) = app.selected_session_name().map(str::to_string) else {
+            app.set_info_banner("Group unavailable", "Select a session to group with.");
+            return;
+        };
+        app.open_input_modal(InputIntent::GroupedSession, format!("{name}-2"));
+        return;
+    }
+
+    if app.focus != FocusArea::Windows {
+        app.set_info_banner(
+            "Clone unavailable",
+            "Focus the Windows list to clone a window.",
+        );
+        return;
+    }
+
+    let Some(window) = app.get_selected_window().cloned() else {
+        app.set_info_banner("Clone unavailable", "Select a window before cloning it.");
+        return;
+    };
+
+    match tmux::clone_window(&window.id) {
+        Ok(new_window_id) => {
+            app.refresh_all();
+            app.focus = FocusArea::Windows;
+            app.select_window_by_id(&new_window_id);
+            app.set_success_banner(
+                "Window cloned",
+                format!(
+                    "A copy of `{}` opened next to it in the same directory.",
+                    window.name
+                ),
+            );
+        }
+        Err(err) => app.set_error_banner(
+            "Could not clone window",
+            format_user_error("Clone failed", err),
+        ),
+    }
+}
+
+fn handle_session_hook_action(app: &mut App) {
+    if app.get_selected_session().is_none() {
+        app.set_info_banner("Hooks unavailable", "Select a session to add a hook to.");
+        return;
+    }
+
+    let items = app.session_hook_items();
+    app.open_picker_modal(PickerIntent::Hooks, items, Some(0));
+}
+
+fn handle_destroy_unattached_action(app: &mut App) {
+    let Some(session) = app.get_selected_session().cloned() else {
+        app.set_info_banner(
+            "Option unavailable",
+            "Select a session to change destroy-unattached.",
+        );
+        return;
+    };
+
+    let enable = !session.destroy_unattached;
+    if enable && session.attached == 0 {
+        app.set_warning_banner(
+            "Session is detached",
+            format!(
+                "tmux would destroy `{}` immediately. Attach a client first.",
+                session.name
+            ),
+        );
+        return;
+    }
+
+    match tmux::set_destroy_unattached(&session.id, enable) {
+        Ok(()) => {
+            app.refresh_all();
+            if enable {
+                app.set_success_banner(
+                    "Session is ephemeral",
+                    format!(
+                        "`{}` will be destroyed when its last client detaches.",
+                        session.name
+                    ),
+                );
+            } else {
+                app.set_success_banner(
+                    "Session kept",
+                    format!("`{}` stays around after clients detach.", session.name),
+                );
+            }
+        }
+        Err(err) => app.set_error_banner(
+            "Could not change option",
+            format_user_error("destroy-unattached failed", err),
+        ),
+    }
+}
+
+fn handle_last_window_action(app: &mut App) {
+    if app.focus != FocusArea::Windows {
+        app.set_info_banner(
+            "Last window unavailable",
+            "Focus the Windows list to switch to the previous window.",
+        );
+        return;
+    }
+
+    let Some(session_id) = app.get_selected_session().map(|session| session.id.clone()) else {
+        app.set_info_banner("Last window unavailable", "Select a session first.");
+        return;
+    };
+
+    match tmux::last_window(&session_id) {
+        Ok(()) => {
+            app.refresh_all();
+            app.select_active_window();
+            let name = app
+                .selected_window_name()
+                .unwrap_or("The last window")
+                .to_string();
+            app.set_info_banner(
+                "Switched windows",
+                format!("`{name}` is the session's active window again."),
+            );
+        }
+        Err(err) => app.set_warning_banner(
+            "No last window",
+            format_user_error("last-window failed", err),
+        ),
+    }
+}
+
+fn handle_step_window_action(app: &mut App, forward: bool) {
+    let Some(session_id) = app.get_selected_session().map(|session| session.id.clone()) else {
+        app.set_info_banner("Window switch unavailable", "Select a session first.");
+        return;
+    };
+
+    match tmux::step_window(&session_id, forward) {
+        Ok(()) => {
+            app.refresh_all();
+            app.select_active_window();
+            let name = app
+                .selected_window_name()
+                .unwrap_or("The new window")
+                .to_string();
+            app.set_info_banner(
+                "Switched windows",
+                format!("`{name}` is now the session's active window."),
+            );
+        }
+        Err(err) => app.set_warning_banner(
+            "Could not switch windows",
+            format_user_error(
+                if forward {
+                    "next-window failed"
+                } else {
+                    "previous-window failed"
+                },
+                err,
+            ),
+        ),
+    }
+}
+
+fn handle_duplicate_window_action(app: &mut App) {
+    if app.focus != FocusArea::Windows {
+        app.set_info_banner(
+            "Duplicate unavailable",
+            "Focus the Windows list to copy a window into a new session.",
+        );
+        return;
+    }
+
+    let Some(name) = app.get_selected_window().map(|window| window.name.clone()) else {
+        app.set_info_banner(
+            "Duplicate unavailable",
+            "Select a window before duplicating it.",
+        );
+        return;
+    };
+
+    app.open_input_modal(InputIntent::DuplicateWindow, name);
+}
+
+fn handle_move_window_action(app: &mut App, down: bool) {
+    if app.focus != FocusArea::Windows {
+        app.set_info_banner(
+            "Reorder unavailable",
+            "Focus the Windows list to move a window.",
+        );
+        return;
+    }
+
+    let Some(window) = app.get_selected_window().cloned() else {
+        app.set_info_banner("Reorder unavailable", "Select a window before moving it.");
+        return;
+    };
+
+    let Some(neighbor) = app.window_neighbor(down).cloned() else {
+        app.set_info_banner(
+            "Nothing to swap",
+            format!(
+                "`{}` is already the {} window.",
+                window.name,
+                if down { "last" } else { "first" }
+            ),
+        );
+        return;
+    };
+
+    match tmux::swap_window(&window.id, &neighbor.id) {
+        Ok(()) => {
+            app.refresh_all();
+            app.set_info_banner(
+                "Window moved",
+                format!("`{}` swapped places with `{}`.", window.name, neighbor.name),
+            );
+        }
+        Err(err) => app.set_error_banner(
+            "Could not move window",
+            format_user_error("swap-window failed", err),
+        ),
+    }
+}
+
+fn handle_equalize_action(app: &mut App) {
+    if app.focus == FocusArea::Sessions {
+        app.set_info_banner(
+            "Equalize unavailable",
+            "Focus the Windows or Panes list to even out a window's panes.",
+        );
+        return;
+    }
+
+    let Some(window) = app.get_selected_window().cloned() else {
+        app.set_info_banner("Equalize unavailable", "Select a window first.");
+        return;
+    };
+
+    let Some(layout) = app::equalized_layout(&window.layout) else {
+        app.set_info_banner(
+            "Nothing to equalize",
+            format!("`{}` has a single pane.", window.name),
+        );
+        return;
+    };
+
+    match tmux::select_layout(&window.id, layout) {
+        Ok(()) => {
+            app.refresh_all();
+            app.set_success_banner(
+                "Panes equalized",
+                format!("`{}` now uses the `{layout}` layout.", window.name),
+            );
+        }
+        Err(err) => app.set_error_banner(
+            "Could not equalize panes",
+            format_user_error("select-layout failed", err),
+        ),
+    }
+}
+
+fn handle_rotate_window_action(app: &mut App) {
+    if app.focus == FocusArea::Sessions {
+        app.set_info_banner(
+            "Rotate unavailable",
+            "Focus the Windows or Panes list to rotate a window's panes.",
+        );
+        return;
+    }
+
+    let Some(window) = app.get_selected_window().cloned() else {
+        app.set_info_banner("Rotate unavailable", "Select a window first.");
+        return;
+    };
+
+    if app::layout_pane_count(&window.layout) < 2 {
+        app.set_info_banner(
+            "Nothing to rotate",
+            format!("`{}` has a single pane.", window.name),
+        );
+        return;
+    }
+
+    match tmux::rotate_window(&window.id) {
+        Ok(()) => {
+            app.refresh_all();
+            app.set_success_banner(
+                "Panes rotated",
+                format!("Each pane in `{}` moved one position forward.", window.name),
+            );
+        }
+        Err(err) => app.set_error_banner(
+            "Could not rotate panes",
+            format_user_error("rotate-window failed", err),
+        ),
+    }
+}
+
+fn handle_tile_action(app: &mut App) {
+    if app.focus == FocusArea::Sessions {
+        app.set_info_banner(
+            "Tiling unavailable",
+            "Focus the Windows or Panes list to tile a window.",
+        );
+        return;
+    }
+
+    let Some(window) = app.get_selected_window().cloned() else {
+        app.set_info_banner("Tiling unavailable", "Select a window before tiling it.");
+        return;
+    };
+
+    if let Some(prior) = app.layout_backups.get(&window.id).cloned() {
+        match tmux::select_layout(&window.id, &prior) {
+            Ok(()) => {
+                app.layout_backups.remove(&window.id);
+                app.refresh_all();
+                app.set_success_banner(
+                    "Layout restored",
+                    format!(
+                        "`{}` is back to its layout from before tiling.",
+                        window.name
+                    ),
+                );
+            }
+            Err(err) => app.set_error_banner(
+                "Could not restore layout",
+                format_user_error("Layout failed", err),
+            ),
+        }
+        return;
+    }
+
+    match tmux::select_layout(&window.id, "tiled") {
+        Ok(()) => {
+            app.layout_backups
+                .insert(window.id.clone(), window.layout.clone());
+            app.refresh_all();
+            app.set_success_banner(
+                "Panes tiled",
+                format!(
+                    "`{}` uses the tiled layout. Press t again to restore the old one.",
+                    window.name
+                ),
+            );
+        }
+        Err(err) => app.set_error_banner(
+            "Could not tile panes",
+            format_user_error("Layout failed", err),
+        ),
+    }
+}
+
+fn handle_watch_pane_action(app: &mut App) {
+    let Some(pane) = app.get_selected_pane().cloned() else {
+        app.set_info_banner("Watch unavailable", "Select a pane before watching it.");
+        return;
+    };
+
+    if app.is_pane_watched(&pane.id) {
+        app.toggle_pane_watch(&pane.id, &pane.current_command);
+        app.set_info_banner(
+            "Watch stopped",
+            format!("`{}` is no longer watched.", pane.id),
+        );
+        return;
+    }
+
+    match tmux::pane_is_idle(&pane.id) {
+        Ok(true) => app.set_info_banner(
+            "Nothing to watch",
+            format!(
+                "`{}` is at its shell prompt. Start a command first.",
+                pane.id
+            ),
+        ),
+        Ok(false) => {
+            app.toggle_pane_watch(&pane.id, &pane.current_command);
+            app.set_info_banner(
+                "Watching pane",
+                format!(
+                    "You'll be notified when `{}` finishes in `{}`.",
+                    pane.current_command, pane.id
+                ),
+            );
+        }
+        Err(err) => app.set_error_banner(
+            "Could not watch pane",
+            format_user_error("Watch failed", err),
+        ),
+    }
+}
+
+fn poll_watched_panes(app: &mut App) {
+    let finished: Vec<_> = app
+        .watched_panes
+        .iter()
+        .filter(|watched| tmux::pane_is_idle(&watched.pane_id).unwrap_or(true))
+        .cloned()
+        .collect();
+    if finished.is_empty() {
+        return;
+    }
+
+    app.watched_panes
+        .retain(|watched| !finished.contains(watched));
+    app.refresh_all();
+    let summary = finished
+        .iter()
+        .map(|watched| format!("`{}` in `{}`", watched.command, watched.pane_id))
+        .collect::<Vec<_>>()
+        .join(", ");
+    app.set_success_banner("Command finished", format!("{summary} finished."));
+
+    if app.config.watch_bell {
+        let mut stdout = std::io::stdout();
+        let _ = stdout.write_all(b"\x07");
+        let _ = stdout.flush();
+    }
+}
+
+fn handle_save_layout_action(app: &mut App) {
+    if app.focus != FocusArea::Windows {
+        app.set_info_banner(
+            "Save unavailable",
+            "Focus the Windows list to save a window's layout.",
+        );
+        return;
+    }
+
+    if app.get_selected_window().is_none() {
+        app.set_info_banner(
+            "Save unavailable",
+            "Select a window before saving its layout.",
+        );
+        return;
+    }
+
+    app.open_input_modal(InputIntent::SaveLayout, "");
+}
+
+fn handle_builtin_layouts_action(app: &mut App) {
+    if app.focus == FocusArea::Sessions || app.get_selected_window().is_none() {
+        app.set_info_banner(
+            "Layouts unavailable",
+            "Select a window in the Windows or Panes list to change its layout.",
+        );
+        return;
+    }
+
+    let items = app.builtin_layout_items();
+    app.open_picker_modal(PickerIntent::Layouts, items, Some(0));
+}
+
+fn handle_cycle_layout_action(app: &mut App) {
+    let Some(window) = app.get_selected_window().cloned() else {
+        app.set_info_banner(
+            "Layouts unavailable",
+            "Select a window to change its layout.",
+        );
+        return;
+    };
+
+    let layout = app.next_builtin_layout(&window.id);
+    apply_builtin_layout(app, &window, layout);
+}
+
+fn apply_builtin_layout(app: &mut App, window: &models::Window, layout: &str) {
+    match tmux::select_layout(&window.id, layout) {
+        Ok(()) => {
+            app.refresh_all();
+            app.set_success_banner(
+                "Layout applied",
+                format!("`{}` now uses `{layout}`.", window.name),
+            );
+        }
+        Err(err) => app.set_error_banner(
+            "Could not apply layout",
+            format_user_error("select-layout failed", err),
+        ),
+    }
+}
+
+fn handle_layout_presets_action(app: &mut App) {
+    if app.focus == FocusArea::Sessions || app.get_selected_window().is_none() {
+        app.set_info_banner(
+            "Layouts unavailable",
+            "Select a window in the Windows or Panes list to apply a saved layout.",
+        );
+        return;
+    }
+
+    match config::load_layout_presets() {
+        Ok(presets) if presets.is_empty() => app.set_info_banner(
+            "No saved layouts",
+            "Press w in the Windows list to save the selected window's layout.",
+        ),
+        Ok(presets) => {
+            app.layout_presets = presets;
+            let items = app.layout_preset_items();
+            app.open_picker_modal(PickerIntent::LayoutPresets, items, Some(0));
+        }
+        Err(err) => app.set_error_banner(
+            "Could not load layouts",
+            format_user_error("Reading presets failed", err),
+        ),
+    }
+}
+
+fn handle_delete_layout_preset_action(app: &mut App) {
+    let Some(modal) = app.picker_modal().cloned() else {
+        return;
+    };
+    let Some(index) = modal.selected_index() else {
+        app.set_modal_error("Choose a layout to delete.");
+        return;
+    };
+
+    let mut presets = app.layout_presets.clone();
+    let removed = presets.remove(index);
+    if let Err(err) = config::save_layout_presets(&presets) {
+        app.set_modal_error(format_user_error("Could not delete layout", err));
+        return;
+    }
+
+    app.layout_presets = presets;
+    if app.layout_presets.is_empty() {
+        app.close_modal();
+    } else {
+        let items = app.layout_preset_items();
+        app.open_picker_modal(PickerIntent::LayoutPresets, items, Some(index));
+    }
+    app.set_success_banner(
+        "Layout deleted",
+        format!("`{}` was removed from your saved layouts.", removed.name),
+    );
+}
+
+fn handle_mark_pane_action(app: &mut App) {
+    if app.focus != FocusArea::Panes {
+        app.set_info_banner("Marking unavailable", "Focus the Panes list to mark panes.");
+        return;
+    }
+
+    match app.toggle_pane_mark() {
+        Some(true) => app.set_info_banner(
+            "Pane marked",
+            format!(
+                "{} marked. Mark two panes in one window, then press x to swap them.",
+                app.marked_panes.len()
+            ),
+        ),
+        Some(false) => app.set_info_banner(
+            "Pane unmarked",
+            format!("{} marked.", app.marked_panes.len()),
+        ),
+        None => app.set_info_banner("Marking unavailable", "Select a pane to mark."),
+    }
+}
+
+fn handle_swap_marked_action(app: &mut App) {
+    let (source, target) = match app.marked_pane_pair() {
+        Ok((source, target)) => (source.to_string(), target.to_string()),
+        Err(reason) => {
+            app.set_info_banner("Swap unavailable", reason);
+            return;
+        }
+    };
+
+    match tmux::swap_panes(&source, &target) {
+        Ok(()) => {
+            app.marked_panes.clear();
+            app.refresh_all();
+            app.set_success_banner(
+                "Panes swapped",
+                format!("`{source}` and `{target}` traded places."),
+            );
+        }
+        Err(err) => app.set_error_banner(
+            "Could not swap panes",
+            format_user_error("Swap failed", err),
+        ),
+    }
+}
+
+fn handle_key_bindings_action(app: &mut App) {
+    match tmux::list_keys() {
+        Ok(content) => {
+            let mut pager = PagerState::new("tmux key bindings", &content);
+            pager.start_search();
+            app.open_pager(pager);
+        }
+        Err(err) => app.set_error_banner(
+            "Could not list key bindings",
+            format_user_error("list-keys failed", err),
+        ),
+    }
+}
+
+fn handle_scrollback_action(app: &mut App) {
+    if app.focus != FocusArea::Panes {
+        app.set_info_banner(
+            "Scrollback unavailable",
+            "Focus the Panes list to search a pane's scrollback.",
+        );
+        return;
+    }
+
+    let Some(pane_id) = app.get_selected_pane().map(|pane| pane.id.clone()) else {
+        app.set_info_banner(
+            "Scrollback unavailable",
+            "Select a pane before opening its scrollback.",
+        );
+        return;
+    };
+
+    match tmux::capture_pane_history(&pane_id, false) {
+        Ok(content) => {
+            let mut pager = PagerState::new(format!("Scrollback {pane_id}"), &content);
+            pager.pane_id = Some(pane_id);
+            pager.start_search();
+            app.open_pager(pager);
+        }
+        Err(err) => app.set_error_banner(
+            "Could not capture pane",
+            format_user_error("Capture failed", err),
+        ),
+    }
+}
+
+fn handle_copy_tree_action(app: &mut App) {
+    let Some(session) = app.get_selected_session().cloned() else {
+        app.set_info_banner("Copy unavailable", "Select a session to copy its tree.");
+        return;
+    };
+
+    let tree = match tmux::session_tree(&session) {
+        Ok(tree) => tree,
+        Err(err) => {
+            app.set_error_banner(
+                "Could not copy session",
+                format_user_error("Listing windows and panes failed", err),
+            );
+            return;
+        }
+    };
+
+    match tmux::set_buffer(tmux::TREE_BUFFER, &tree) {
+        Ok(()) => app.set_success_banner(
+            "Session tree copied",
+            format!(
+                "{} lines for `{}` are in buffer `{}` and the clipboard, if tmux can reach it.",
+                tree.lines().count(),
+                session.name,
+                tmux::TREE_BUFFER
+            ),
+        ),
+        Err(err) => app.set_error_banner(
+            "Could not copy session",
+            format_user_error("set-buffer failed", err),
+        ),
+    }
+}
+
+fn handle_copy_scrollback_action(app: &mut App) {
+    if app.focus != FocusArea::Panes {
+        app.set_info_banner(
+            "Copy unavailable",
+            "Focus the Panes list to copy a pane's scrollback into a tmux buffer.",
+        );
+        return;
+    }
+
+    let Some(pane_id) = app.get_selected_pane().map(|pane| pane.id.clone()) else {
+        app.set_info_banner(
+            "Copy unavailable",
+            "Select a pane before copying its scrollback.",
+        );
+        return;
+    };
+
+    let timestamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|elapsed| elapsed.as_secs())
+        .unwrap_or_default();
+    let buffer_name = tmux::scrollback_buffer_name(&pane_id, timestamp);
+
+    match tmux::capture_to_buffer(&pane_id, &buffer_name) {
+        Ok(()) => app.set_success_banner(
+            "Scrollback copied",
+            format!(
+                "Saved to tmux buffer {buffer_name}. Paste it with paste-buffer -b {buffer_name}."
+            ),
+        ),
+        Err(err) => app.set_error_banner(
+            "Could not copy scrollback",
+            format_user_error("Capture failed", err),
+        ),
+    }
+}
+
+fn handle_pipe_action(app: &mut App) {
+    if app.focus != FocusArea::Panes {
+        app.set_info_banner(
+            "Pipe unavailable",
+            "Focus the Panes list to pipe a pane's scrollback.",
+        );
+        return;
+    }
+
+    if app.get_selected_pane().is_none() {
+        app.set_info_banner("Pipe unavailable", "Select a pane to pipe its scrollback.");
+        return;
+    }
+
+    app.open_input_modal(InputIntent::PipeCommand, "");
+}
+
+fn handle_send_keys_action(app: &mut App) {
+    if app.focus != FocusArea::Panes {
+        app.set_info_banner(
+            "Send unavailable",
+            "Focus the Panes list to send a command to a pane.",
+        );
+        return;
+    }
+
+    if app.get_selected_pane().is_none() {
+        app.set_info_banner("Send unavailable", "Select a pane to send keys to.");
+        return;
+    }
+
+    app.open_input_modal(InputIntent::SendKeys, "");
+}
+
+fn handle_windows_from_directory_action(app: &mut App) {
+    if app.focus == FocusArea::Panes {
+        app.set_info_banner(
+            "Bulk create unavailable",
+            "Focus the Sessions or Windows list to add a window per subdirectory.",
+        );
+        return;
+    }
+
+    if app.get_selected_session().is_none() {
+        app.set_info_banner(
+            "Bulk create unavailable",
+            "Select a session to add the windows to.",
+        );
+        return;
+    }
+
+    let path = app
+        .get_selected_window()
+        .map(|window| window.path.clone())
+        .unwrap_or_default();
+    app.open_input_modal(InputIntent::WindowsFromDirectory, path);
+}
+
+fn create_windows_from_directory(
+    session_id: &str,
+    dirs: &[(String, PathBuf)],
+) -> (usize, Option<tmux::TmuxError>) {
+    let mut created = 0;
+    let mut first_error = None;
+    for (name, path) in dirs {
+        match tmux::create_window_in_path(session_id, name, path) {
+            Ok(()) => created += 1,
+            Err(err) => {
+                first_error.get_or_insert(err);
+            }
+        }
+    }
+    (created, first_error)
+}
+
+fn handle_resume_action(app: &mut App) {
+    let own_pane = env::var("TMUX_PANE").ok();
+    match tmux::last_active_pane(own_pane.as_deref()) {
+        Ok(Some(location)) => {
+            if app.jump_to_pane(&location) {
+                handle_attach_action(app);
+            } else {
+                app.set_warning_banner(
+                    "Pane not found",
+                    format!(
+                        "`{}` in {}:{} is hidden by a filter or just closed.",
+                        location.pane_id, location.session_name, location.window_name
+                    ),
+                );
+            }
+        }
+        Ok(None) => app.set_info_banner(
+            "Nothing to resume",
+            "tmux has no record of a recently active pane.",
+        ),
+        Err(err) => app.set_error_banner(
+            "Could not resume",
+            format_user_error("list-panes failed", err),
+        ),
+    }
+}
+
+fn find_pane_by_command(app: &mut App, query: &str) {
+    let matches = match tmux::find_panes_by_command(query) {
+        Ok(matches) => matches,
+        Err(err) => {
+            app.set_error_banner(
+                "Could not search panes",
+                format_user_error("list-panes failed", err),
+            );
+            return;
+        }
+    };
+
+    if matches.is_empty() {
+        app.command_search = None;
+        app.set_info_banner(
+            "No matching pane",
+            format!("No pane on this server is running `{query}`."),
+        );
+        return;
+    }
+
+    let position = app.advance_command_search(query, matches.len());
+    let location = &matches[position];
+    if app.jump_to_pane(location) {
+        app.set_info_banner(
+            format!("Match {} of {}", position + 1, matches.len()),
+            format!(
+                "`{}` runs in {}:{} ({}). Press F for the next match.",
+                location.command, location.session_name, location.window_name, location.pane_id
+            ),
+        );
+    } else {
+        app.set_warning_banner(
+            "Match hidden",
+            format!(
+                "`{}` is in session `{}`, which the session filter hides. Press A to show it.",
+                location.pane_id, location.session_name
+            ),
+        );
+    }
+}
+
+fn handle_move_window_to_session_action(app: &mut App) {
+    if app.get_selected_window().is_none() {
+        app.set_info_banner("Move unavailable", "Select a window before moving it.");
+        return;
+    }
+
+    app.open_input_modal(InputIntent::MoveWindow, "");
+}
+
+fn handle_merge_sessions_action(app: &mut App) {
+    if app.get_selected_session().is_none() {
+        app.set_info_banner("Merge unavailable", "Select the session to merge into.");
+        return;
+    }
+
+    let items = app.merge_source_items();
+    if items.is_empty() {
+        app.set_info_banner(
+            "Nothing to merge",
+            "There is no other session to move windows from.",
+        );
+        return;
+    }
+
+    app.open_picker_modal(PickerIntent::MergeSources, items, Some(0));
+}
+
+fn handle_join_pane_action(app: &mut App) {
+    let Some(session_id) = app.get_selected_session().map(|session| session.id.clone()) else {
+        app.set_info_banner("Join unavailable", "Select a session and window first.");
+        return;
+    };
+    if app.get_selected_window().is_none() {
+        app.set_info_banner("Join unavailable", "Select the window to join a pane into.");
+        return;
+    }
+
+    match tmux::session_panes(&session_id) {
+        Ok(panes) => {
+            app.set_join_sources(panes);
+            let items = app.join_source_items();
+            if items.is_empty() {
+                app.set_info_banner(
+                    "Nothing to join",
+                    "Every pane in this session is already in the selected window.",
+                );
+                return;
+            }
+            app.open_picker_modal(PickerIntent::JoinSources, items, Some(0));
+        }
+        Err(err) => app.set_error_banner(
+            "Could not list panes",
+            format_user_error("list-panes failed", err),
+        ),
+    }
+}
+
+fn handle_recently_killed_action(app: &mut App) {
+    if app.recently_killed.is_empty() {
+        app.set_info_banner(
+            "Nothing to recreate",
+            "Sessions you delete here are listed for quick recreation.",
+        );
+        return;
+    }
+
+    let items = app.recently_killed_items();
+    app.open_picker_modal(PickerIntent::RecentlyKilled, items, Some(0));
+}
+
+fn handle_sockets_action(app: &mut App) {
+    match tmux::list_sockets() {
+        Ok(sockets) if sockets.is_empty() => app.set_info_banner(
+            "No other servers",
+            "No tmux sockets were found for this user.",
+        ),
+        Ok(sockets) => {
+            app.sockets = sockets;
+            let selected = app
+                .sockets
+                .iter()
+                .position(|name| Some(name) == app.socket.as_ref());
+            let items = app.socket_items();
+            app.open_picker_modal(PickerIntent::Sockets, items, selected.or(Some(0)));
+        }
+        Err(err) => app.set_error_banner(
+            "Could not list servers",
+            format_user_error("Socket lookup failed", err),
+        ),
+    }
+}
+
+fn handle_clients_action(app: &mut App) {
+    match tmux::list_clients() {
+        Ok(clients) if clients.is_empty() => {
+            app.clients = clients;
+            app.set_info_banner("No clients", "No terminal is attached to this tmux server.");
+        }
+        Ok(clients) => {
+            app.clients = clients;
+            let items = app.client_items();
+            app.open_picker_modal(PickerIntent::Clients, items, Some(0));
+        }
+        Err(err) => app.set_error_banner(
+            "Could not list clients",
+            format_user_error("list-clients failed", err),
+        ),
+    }
+}
+
+fn handle_buffers_action(app: &mut App) {
+    match tmux::get_buffers() {
+        Ok(buffers) if buffers.is_empty() => {
+            app.buffers = buffers;
+            app.set_info_banner(
+                "No buffers",
+                "tmux has no paste buffers yet. Y copies a pane's scrollback into one.",
+            );
+        }
+        Ok(buffers) => {
+            app.buffers = buffers;
+            let items = app.buffer_items();
+            app.open_picker_modal(PickerIntent::Buffers, items, Some(0));
+        }
+        Err(err) => app.set_error_banner(
+            "Could not list buffers",
+            format_user_error("list-buffers failed", err),
+        ),
+    }
+}
+
+fn handle_delete_buffer_action(app: &mut App) {
+    let Some(modal) = app.picker_modal().cloned() else {
+        return;
+    };
+    let Some(buffer_name) = modal
+        .selected_index()
+        .and_then(|index| app.buffers.get(index))
+        .map(|buffer| buffer.name.clone())
+    else {
+        app.set_modal_error("Choose a buffer to delete.");
+        return;
+    };
+
+    if let Err(err) = tmux::delete_buffer(&buffer_name) {
+        app.set_modal_error(format_user_error("Could not delete buffer", err));
+        return;
+    }
+
+    match tmux::get_buffers() {
+        Ok(buffers) => {
+            app.buffers = buffers;
+            if app.buffers.is_empty() {
+                app.close_modal();
+            } else {
+                let items = app.buffer_items();
+                app.open_picker_modal(PickerIntent::Buffers, items, modal.selected_index());
+            }
+            app.set_success_banner("Buffer deleted", format!("`{buffer_name}` was removed."));
+        }
+        Err(err) => {
+            app.close_modal();
+            app.set_error_banner(
+                "Could not list buffers",
+                format_user_error("list-buffers failed", err),
+            );
+        }
+    }
+}
+
+fn handle_kill_server_action(app: &mut App) {
+    if app.connection != tmux::TmuxConnectionState::Connected {
+        app.set_info_banner("No server", "There is no tmux server running to stop.");
+        return;
+    }
+
+    app.open_confirm_modal(ConfirmIntent::KillServer);
+}
+
+fn handle_attach_command_action(app: &mut App) {
+    if app.get_selected_session().is_none() {
+        app.set_info_banner(
+            "Attach unavailable",
+            "Select a session, window, or pane to attach to.",
+        );
+        return;
+    }
+
+    app.open_input_modal(InputIntent::AttachCommand, "");
+}
+
+fn handle_attach_action(app: &mut App) {
+    if app.needs_attach_confirmation() {
+        app.open_confirm_modal(ConfirmIntent::Attach);
+        return;
+    }
+
+    attach_selection(app);
+}
+
+fn attach_selection(app: &mut App) {
+    match app.focus {
+        FocusArea::Sessions => {
+            let target = app
+                .get_selected_session()
+                .map(|session| session.name.clone());
+            if let Some(target) = target {
+                app.target_attach = Some(target);
+                app.should_quit = true;
+            } else {
+                app.set_info_banner("Attach unavailable", "Select a session to attach.");
+            }
+        }
+        FocusArea::Windows => {
+            let session_name = app
+                .get_selected_session()
+                .map(|session| session.name.clone());
+            let window_id = app.get_selected_window().map(|window| window.id.clone());
+
+            match (session_name, window_id) {
+                (Some(session_name), Some(window_id)) => {
+                    app.target_attach =
+                        Some(tmux::attach_target(&session_name, Some(&window_id), None));
+                    app.should_quit = true;
+                }
+                _ => app.set_info_banner("Attach unavailable", "Select a window to attach."),
+            }
+        }
+        FocusArea::Panes => {
+            let session_name = app
+                .get_selected_session()
+                .map(|session| session.name.clone());
+            let window_id = app.get_selected_window().map(|window| window.id.clone());
+            let pane_id = app.get_selected_pane().map(|pane| pane.id.clone());
+
+            match (session_name, window_id, pane_id) {
+                (Some(session_name), Some(window_id), Some(pane_id)) => {
+                    app.target_attach = Some(tmux::attach_target(
+                        &session_name,
+                        Some(&window_id),
+                        Some(&pane_id),
+                    ));
+                    app.should_quit = true;
+                }
+                _ => app.set_info_banner("Attach unavailable", "Select a pane to attach."),
+            }
+        }
+    }
+}
+
+fn handle_shell_action(app: &mut App) {
+    let path = app.selected_path().map(str::to_string);
+    if let Some(path) = path {
+        app.target_shell = Some(path);
+        app.should_quit = true;
+    } else {
+        app.set_info_banner(
+            "Shell unavailable",
+            "Select a pane or window with a known directory first.",
+        );
+    }
+}
+
+fn handle_branch_rename_action(app: &mut App) {
+    if app.focus != FocusArea::Windows {
+        app.set_info_banner(
+            "Branch name unavailable",
+            "Focus the Windows list to name a window after its git branch.",
+        );
+        return;
+    }
+
+    let Some(path) = app
+        .get_selected_window()
+        .map(|window| window.path.clone())
+        .filter(|path| !path.is_empty())
+    else {
+        app.set_info_banner(
+            "Branch name unavailable",
+            "Select a window with a known directory first.",
+        );
+        return;
+    };
+
+    match util::git_branch(&path) {
+        Ok(branch) => app.open_input_modal(InputIntent::RenameWindow, branch),
+        Err(err) => app.set_warning_banner(
+            "No git branch",
+            format_user_error("Branch lookup failed", err),
+        ),
+    }
+}
+
+fn handle_action_menu_action(app: &mut App) {
+    let items = app.menu_action_items();
+    app.open_picker_modal(PickerIntent::Actions, items, Some(0));
+}
+
+fn handle_detach_and_quit_action(app: &mut App) {
+    if env::var("TMUX").is_err() {
+        app.set_info_banner(
+            "Nothing to detach",
+            "tmuxui is not running inside tmux. Press q to quit.",
+        );
+        return;
+    }
+
+    app.detach_and_quit = true;
+    app.should_quit = true;
+}
+
+fn handle_aggressive_resize_action(app: &mut App) {
+    let Some(window) = app.get_selected_window().cloned() else {
+        app.set_info_banner(
+            "Option unavailable",
+            "Select a window to change aggressive-resize.",
+        );
+        return;
+    };
+
+    let enable = !window.aggressive_resize;
+    match tmux::set_aggressive_resize(&window.id, enable) {
+        Ok(()) => {
+            app.refresh_all();
+            app.set_success_banner(
+                if enable {
+                    "Aggressive resize on"
+                } else {
+                    "Aggressive resize off"
+                },
+                if enable {
+                    format!(
+                        "`{}` sizes to the smallest client viewing it, not every attached client.",
+                        window.name
+                    )
+                } else {
+                    format!(
+                        "`{}` sizes to the smallest client attached to its session.",
+                        window.name
+                    )
+                },
+            );
+        }
+        Err(err) => app.set_error_banner(
+            "Could not change aggressive-resize",
+            format_user_error("set-window-option failed", err),
+        ),
+    }
+}
+
+fn handle_synchronize_panes_action(app: &mut App) {
+    if app.focus != FocusArea::Windows {
+        app.set_info_banner(
+            "Sync unavailable",
+            "Focus the Windows list to synchronize a window's panes.",
+        );
+        return;
+    }
+
+    let Some(window) = app.get_selected_window().cloned() else {
+        app.set_info_banner(
+            "Sync unavailable",
+            "Select a window to change synchronize-panes.",
+        );
+        return;
+    };
+
+    let enable = !window.synchronized;
+    match tmux::set_synchronize_panes(&window.id, enable) {
+        Ok(()) => {
+            app.refresh_all();
+            if enable {
+                app.set_warning_banner(
+                    "Panes synchronized",
+                    format!(
+                        "Typing in any pane of `{}` now goes to all of its panes.",
+                        window.name
+                    ),
+                );
+            } else {
+                app.set_success_banner(
+                    "Panes independent",
+                    format!("Each pane of `{}` gets its own input again.", window.name),
+                );
+            }
+        }
+        Err(err) => app.set_error_banner(
+            "Could not change synchronize-panes",
+            format_user_error("set-window-option failed", err),
+        ),
+    }
+}
+
+fn handle_toggle_mouse_action(app: &mut App) {
+    let Some(enabled) = app.mouse else {
+        app.set_info_banner(
+            "Mouse unavailable",
+            "Connect to a tmux server to change its mouse option.",
+        );
+        return;
+    };
+
+    match tmux::set_mouse(!enabled) {
+        Ok(()) => {
+            app.refresh_all();
+            app.set_success_banner(
+                if enabled { "Mouse off" } else { "Mouse on" },
+                "tmux's global mouse option changed for every session.",
+            );
+        }
+        Err(err) => app.set_error_banner(
+            "Could not change mouse option",
+            format_user_error("Mouse toggle failed", err),
+        ),
+    }
+}
+
+fn handle_status_position_action(app: &mut App) {
+    match tmux::toggle_status_position() {
+        Ok(position) => app.set_success_banner(
+            "Status bar moved",
+            format!("tmux now shows the status bar at the {position} in every session."),
+        ),
+        Err(err) => app.set_error_banner(
+            "Could not move status bar",
+            format_user_error("status-position toggle failed", err),
+        ),
+    }
+}
+
+fn handle_export_command_action(app: &mut App) {
+    if app.focus != FocusArea::Panes {
+        app.set_info_banner(
+            "Copy unavailable",
+            "Focus the Panes list to copy a pane's command.",
+        );
+        return;
+    }
+
+    let Some(pane) = app.get_selected_pane().cloned() else {
+        app.set_info_banner(
+            "Copy unavailable",
+            "Select a pane before copying its command.",
+        );
+        return;
+    };
+
+    let command = tmux::pane_start_command(&pane.id)
+        .ok()
+        .filter(|command| !command.is_empty())
+        .unwrap_or(pane.current_command);
+
+    match tmux::set_buffer(tmux::COMMAND_BUFFER, &command) {
+        Ok(()) => app.set_success_banner(
+            "Command copied",
+            format!(
+                "`{command}` is in buffer `{}` and the clipboard, if tmux can reach it.",
+                tmux::COMMAND_BUFFER
+            ),
+        ),
+        Err(err) => app.set_error_banner(
+            "Could not copy command",
+            format_user_error("set-buffer failed", err),
+        ),
+    }
+}
+
+fn handle_copy_path_action(app: &mut App) {
+    if app.focus != FocusArea::Panes {
+        app.set_info_banner(
+            "Copy unavailable",
+            "Focus the Panes list to copy a pane's directory.",
+        );
+        return;
+    }
+
+    let Some(path) = app
+        .get_selected_pane()
+        .map(|pane| pane.current_path.clone())
+        .filter(|path| !path.is_empty())
+    else {
+        app.set_info_banner(
+            "Copy unavailable",
+            "Select a pane with a known directory before copying it.",
+        );
+        return;
+    };
+
+    match tmux::set_buffer(tmux::PATH_BUFFER, &path) {
+        Ok(()) => app.set_success_banner(
+            "Path copied",
+            format!(
+                "`{path}` is in buffer `{}` and the clipboard, if tmux can reach it.",
+                tmux::PATH_BUFFER
+            ),
+        ),
+        Err(err) => app.set_error_banner(
+            "Could not copy path",
+            format_user_error("set-buffer failed", err),
+        ),
+    }
+}
+
+fn handle_next_active_pane_action(app: &mut App) {
+    if app.focus != FocusArea::Panes {
+        app.set_info_banner(
+            "Active pane unavailable",
+            "Focus the Panes list to move tmux's active pane.",
+        );
+        return;
+    }
+
+    let Some(window_id) = app.get_selected_window().map(|window| window.id.clone()) else {
+        app.set_info_banner(
+            "Active pane unavailable",
+            "Select a window with panes first.",
+        );
+        return;
+    };
+
+    match tmux::select_next_pane(&window_id) {
+        Ok(()) => {
+            app.refresh_all();
+            app.select_active_pane();
+            let pane_id = app
+                .selected_pane_id()
+                .unwrap_or("The next pane")
+                .to_string();
+            app.set_info_banner(
+                "Active pane moved",
+                format!("`{pane_id}` is now active. Enter attaches with it focused."),
+            );
+        }
+        Err(err) => app.set_error_banner(
+            "Could not change active pane",
+            format_user_error("select-pane failed", err),
+        ),
+    }
+}
+
+fn handle_zoom_pane_action(app: &mut App) {
+    if app.focus != FocusArea::Panes {
+        app.set_info_banner("Zoom unavailable", "Focus the Panes list to zoom a pane.");
+        return;
+    }
+
+    let Some(pane) = app.get_selected_pane().cloned() else {
+        app.set_info_banner("Zoom unavailable", "Select a pane before zooming it.");
+        return;
+    };
+
+    match tmux::toggle_zoom(&pane.id) {
+        Ok(()) => {
+            app.refresh_panes_only();
+            if pane.zoomed {
+                app.set_info_banner(
+                    "Pane unzoomed",
+                    "Every pane in the window is visible again.",
+                );
+            } else {
+                app.set_info_banner(
+                    "Pane zoomed",
+                    format!("`{}` fills its window. Press z again to unzoom.", pane.id),
+                );
+            }
+        }
+        Err(err) => app.set_error_banner(
+            "Could not zoom pane",
+            format_user_error("resize-pane failed", err),
+        ),
+    }
+}
+
+fn handle_resize_pane_action(app: &mut App, direction: tmux::ResizeDirection) {
+    if app.focus != FocusArea::Panes {
+        app.set_info_banner(
+            "Resize unavailable",
+            "Focus the Panes list to resize a pane.",
+        );
+        return;
+    }
+
+    let Some(pane_id) = app.selected_pane_id().map(str::to_string) else {
+        app.set_info_banner("Resize unavailable", "Select a pane before resizing it.");
+        return;
+    };
+
+    if app.panes.len() < 2 {
+        app.set_info_banner(
+            "Nothing to resize",
+            "This window has a single pane that already fills it.",
+        );
+        return;
+    }
+
+    match tmux::resize_pane(&pane_id, direction, PANE_RESIZE_STEP) {
+        Ok(()) => {
+            app.refresh_panes_only();
+            if let Some(pane) = app.get_selected_pane() {
+                let size = format!("{}x{}", pane.width, pane.height);
+                app.set_info_banner("Pane resized", format!("`{pane_id}` is now {size}."));
+            }
+        }
+        Err(err) => app.set_error_banner(
+            "Could not resize pane",
+            format_user_error("resize-pane failed", err),
+        ),
+    }
+}
+
+fn handle_break_pane_action(app: &mut App) {
+    let Some(pane_id) = app.selected_pane_id().map(str::to_string) else {
+        app.set_info_banner(
+            "Break unavailable",
+            "Select a pane before moving it to its own window.",
+        );
+        return;
+    };
+
+    if app.panes.len() < 2 {
+        app.set_info_banner(
+            "Nothing to break out",
+            "This pane is already alone in its window.",
+        );
+        return;
+    }
+
+    match tmux::break_pane(&pane_id) {
+        Ok(window_id) => {
+            app.refresh_all();
+            app.focus = FocusArea::Windows;
+            app.select_window_by_id(&window_id);
+            app.set_success_banner(
+                "Pane broken out",
+                format!("`{pane_id}` now has its own window in the same session."),
+            );
+        }
+        Err(err) => app.set_error_banner(
+            "Could not break pane",
+            format_user_error("break-pane failed", err),
+        ),
+    }
+}
+
+fn handle_display_panes_action(app: &mut App) {
+    if env::var("TMUX").is_err() {
+        app.set_info_banner(
+            "Pane numbers unavailable",
+            "Run tmuxui inside tmux to flash pane numbers on the attached client.",
+        );
+        return;
+    }
+
+    match tmux::display_panes() {
+        Ok(()) => app.set_info_banner(
+            "Pane numbers shown",
+            "tmux is showing the pane numbers on this client.",
+        ),
+        Err(err) => app.set_error_banner(
+            "Could not show pane numbers",
+            format_user_error("display-panes failed", err),
+        ),
+    }
+}
+
+fn handle_input_submission(app: &mut App) {
+    let Some(modal) = app.input_modal().cloned() else {
+        return;
+    };
+
+    let value = modal.value.trim().to_string();
+    if let Some(error) = app::validate_name(modal.intent, &value) {
+        app.set_modal_error(error);
+        return;
+    }
+
+    match modal.intent {
+        InputIntent::AttachCommand => {
+            app.close_modal();
+            app.attach_command = Some(value);
+            handle_attach_action(app);
+            if !app.should_quit && app.confirm_modal().is_none() {
+                app.attach_command = None;
+            }
+        }
+        InputIntent::SaveLayout => {
+            let Some(layout) = app
+                .get_selected_window()
+                .map(|window| window.layout.clone())
+            else {
+                app.close_modal();
+                app.set_warning_banner(
+                    "Selection changed",
+                    "Select a window and try saving its layout again.",
+                );
+                return;
+            };
+
+            let mut presets = match config::load_layout_presets() {
+                Ok(presets) => presets,
+                Err(err) => {
+                    app.set_modal_error(format_user_error("Could not load layouts", err));
+                    return;
+                }
+            };
+            let replaced = presets.iter().any(|preset| preset.name == value);
+            presets.retain(|preset| preset.name != value);
+            presets.push(LayoutPreset {
+                name: value.clone(),
+                layout,
+            });
+
+            match config::save_layout_presets(&presets) {
+                Ok(()) => {
+                    app.layout_presets = presets;
+                    app.close_modal();
+                    app.set_success_banner(
+                        if replaced {
+                            "Layout replaced"
+                        } else {
+                            "Layout saved"
+                        },
+                        format!("Press L on any window to apply `{value}`."),
+                    );
+                }
+                Err(err) => app.set_modal_error(format_user_error("Could not save layout", err)),
+            }
+        }
+        InputIntent::DuplicateWindow => {
+            let Some(window) = app.get_selected_window().cloned() else {
+                app.close_modal();
+                app.set_warning_banner(
+                    "Selection changed",
+                    "Select a window and try duplicating it again.",
+                );
+                return;
+            };
+
+            if app.session_name_taken(&value, None) {
+                app.set_modal_error(format!(
+                    "A session named `{value}` already exists. Choose another name."
+                ));
+                return;
+            }
+
+            match tmux::duplicate_window_to_session(&window, &value, Some(session_size_hint())) {
+                Ok(()) => {
+                    app.close_modal();
+                    app.focus = FocusArea::Sessions;
+                    app.refresh_all();
+                    app.select_session_by_name(&value);
+                    app.set_success_banner(
+                        "Window duplicated",
+                        format!(
+                            "`{value}` starts with a copy of `{}` and its pane commands.",
+                            window.name
+                        ),
+                    );
+                }
+                Err(err) => {
+                    app.refresh_all();
+                    app.set_modal_error(format_user_error("Could not duplicate window", err));
+                }
+            }
+        }
+        InputIntent::PipeCommand => {
+            let Some(pane_id) = app.get_selected_pane().map(|pane| pane.id.clone()) else {
+                app.close_modal();
+                app.set_warning_banner(
+                    "Selection changed",
+                    "Select a pane and try piping it again.",
+                );
+                return;
+            };
+
+            match tmux::capture_pane_history(&pane_id, false) {
+                Ok(content) => {
+                    app.close_modal();
+                    app.pending_pipe = Some(PendingPipe {
+                        pane_id,
+                        command: value,
+                        content,
+                    });
+                }
+                Err(err) => app.set_modal_error(format_user_error("Could not capture pane", err)),
+            }
+        }
+        InputIntent::KillServer => {
+            if value != "yes" {
+                app.set_modal_error("Type `yes` exactly to stop the server, or press Esc.");
+                return;
+            }
+
+            match tmux::kill_server() {
+                Err(err) if !err.is_no_server() => {
+                    app.set_modal_error(format_user_error("kill-server failed", err))
+                }
+                Ok(()) | Err(_) => {
+                    app.close_modal();
+                    app.focus = FocusArea::Sessions;
+                    app.refresh_all();
+                    app.set_success_banner(
+                        "Server stopped",
+                        "Every session is gone. Press n to start a new one.",
+                    );
+                }
+            }
+        }
+        InputIntent::PaneTitle => {
+            let Some(pane_id) = app.get_selected_pane().map(|pane| pane.id.clone()) else {
+                app.close_modal();
+                app.set_warning_banner(
+                    "Selection changed",
+                    "Select a pane and try setting its title again.",
+                );
+                return;
+            };
+
+            match tmux::set_pane_title(&pane_id, &value) {
+                Ok(()) => {
+                    app.close_modal();
+                    app.refresh_all();
+                    app.set_success_banner(
+                        "Pane titled",
+                        format!("Pane `{pane_id}` is now titled `{value}`."),
+                    );
+                }
+                Err(err) => app.set_modal_error(format_user_error("Could not set pane title", err)),
+            }
+        }
+        InputIntent::WindowsFromDirectory => {
+            let Some(session_id) = app.get_selected_session().map(|session| session.id.clone())
+            else {
+                app.close_modal();
+                app.set_warning_banner(
+                    "Selection changed",
+                    "Select a session and try creating the windows again.",
+                );
+                return;
+            };
+
+            let parent = util::expand_home(&value, env::var("HOME").ok().as_deref());
+            let dirs = match util::subdirectories(&parent) {
+                Ok(dirs) => dirs,
+                Err(err) => {
+                    app.set_modal_error(format_user_error("Could not list directories", err));
+                    return;
+                }
+            };
+            if dirs.is_empty() {
+                app.set_modal_error(format!("`{value}` has no visible subdirectories."));
+                return;
+            }
+
+            let (created, error) = create_windows_from_directory(&session_id, &dirs);
+            app.close_modal();
+            app.focus = FocusArea::Windows;
+            app.refresh_all();
+            match error {
+                None => app.set_success_banner(
+                    "Windows created",
+                    format!("Added {created} windows, one per subdirectory of `{value}`."),
+                ),
+                Some(err) => app.set_error_banner(
+                    "Some windows failed",
+                    format_user_error(&format!("Created {created} of {} windows", dirs.len()), err),
+                ),
+            }
+        }
+        InputIntent::SendKeys => {
+            let Some(pane_id) = app.get_selected_pane().map(|pane| pane.id.clone()) else {
+                app.close_modal();
+                app.set_warning_banner(
+                    "Selection changed",
+                    "Select a pane and try sending the command again.",
+                );
+                return;
+            };
+
+            match tmux::send_command(&pane_id, &value) {
+                Ok(()) => {
+                    app.close_modal();
+                    app.refresh_panes_only();
+                    app.set_success_banner(
+                        "Command sent",
+                        format!("`{value}` was typed into `{pane_id}` followed by Enter."),
+                    );
+                }
+                Err(err) => app.set_modal_error(format_user_error("Could not send keys", err)),
+            }
+        }
+        InputIntent::FindCommand => {
+            app.close_modal();
+            find_pane_by_command(app, &value);
+        }
+        InputIntent::MoveWindow => {
+            let Some(window) = app.get_selected_window().cloned() else {
+                app.close_modal();
+                app.set_warning_banner(
+                    "Selection changed",
+                    "Select a window and try moving it again.",
+                );
+                return;
+            };
+            let Some(session) = app
+                .sessions
+                .iter()
+                .find(|session| session.name == value)
+                .cloned()
+            else {
+                app.set_modal_error(format!("No session is named `{value}`."));
+                return;
+            };
+            if app.selected_session_name() == Some(value.as_str()) {
+                app.set_modal_error(format!("`{}` is already in `{value}`.", window.name));
+                return;
+            }
+
+            match tmux::move_window(&window.id, &session.id) {
+                Ok(()) => {
+                    app.close_modal();
+                    app.refresh_all();
+                    app.set_success_banner(
+                        "Window moved",
+                        format!("`{}` now lives in session `{value}`.", window.name),
+                    );
+                }
+                Err(err) => app.set_modal_error(format_user_error("Could not move window", err)),
+            }
+        }
+        InputIntent::SessionHook(hook) => {
+            let Some(session) = app.get_selected_session().cloned() else {
+                app.close_modal();
+                app.set_warning_banner(
+                    "Selection changed",
+                    "Select a session and try adding the hook again.",
+                );
+                return;
+            };
+
+            match tmux::set_session_hook(&session.id, hook, &value) {
+                Ok(()) => {
+                    app.close_modal();
+                    app.set_success_banner(
+                        "Hook set",
+                        format!("`{}` now runs `{value}` on {hook}.", session.name),
+                    );
+                }
+                Err(err) => app.set_modal_error(format_user_error("Could not set hook", err)),
+            }
+        }
+        InputIntent::DevSession => {
+            if app.session_name_taken(&value, None) {
+                app.set_modal_error(format!(
+                    "A session named `{value}` already exists. Choose another name."
+                ));
+                return;
+            }
 
-                    app.target_attach = Some(session_name);
-                    app.should_quit = true;
+            match tmux::create_dev_session(&value, Some(session_size_hint())) {
+                Ok(()) => {
+                    app.close_modal();
+                    app.focus = FocusArea::Sessions;
+                    app.refresh_all();
+                    app.select_session_by_name(&value);
+                    app.set_success_banner(
+                        "Dev session created",
+                        format!(
+                            "`{value}` has an editor, a terminal below, and a side pane. Press Enter to attach."
+                        ),
+                    );
+                }
+                Err(err) => {
+                    app.refresh_all();
+                    app.set_modal_error(format_user_error("Could not create dev session", err));
                 }
-                _ => app.set_info_banner("Attach unavailable", "Select a window to attach."),
             }
         }
-        FocusArea::Panes => {
-            let session_name = app
-                .get_selected_session()
-                .map(|session| session.name.clone());
-            let window_id = app.get_selected_window().map(|window| window.id.clone());
-            let pane_id = app.get_selected_pane().map(|pane| pane.id.clone());
-
-            match (session_name, window_id, pane_id) {
-                (Some(session_name), Some(window_id), Some(pane_id)) => {
-                    if let Err(err) = tmux::select_window(&window_id) {
-                        app.set_error_banner(
-                            "Could not select window",
-                            format_user_error("Attach failed", err),
-                        );
-                        return;
-                    }
+        InputIntent::GroupedSession => {
+            let Some(source) = app.get_selected_session().cloned() else {
+                app.close_modal();
+                app.set_warning_banner(
+                    "Selection changed",
+                    "Select a session and try grouping it again.",
+                );
+                return;
+            };
 
-                    if let Err(err) = tmux::select_pane(&pane_id) {
-                        app.set_error_banner(
-                            "Could not select pane",
-                            format_user_error("Attach failed", err),
-                        );
-                        return;
-                    }
+            if app.session_name_taken(&value, None) {
+                app.set_modal_error(format!(
+                    "A session named `{value}` already exists. Choose another name."
+                ));
+                return;
+            }
 
-                    app.target_attach = Some(session_name);
-                    app.should_quit = true;
+            match tmux::new_grouped_session(&source.id, &value, Some(session_size_hint())) {
+                Ok(()) => {
+                    app.close_modal();
+                    app.focus = FocusArea::Sessions;
+                    app.refresh_all();
+                    app.select_session_by_name(&value);
+                    app.set_success_banner(
+                        "Grouped session created",
+                        format!(
+                            "`{value}` shares its windows with `{}`; each can show a different one.",
+                            source.name
+                        ),
+                    );
+                }
+                Err(err) => {
+                    app.set_modal_error(format_user_error("Could not create grouped session", err))
                 }
-                _ => app.set_info_banner("Attach unavailable", "Select a pane to attach."),
             }
         }
-    }
-}
+        InputIntent::NewSession => {
+            if app.session_name_taken(&value, None) {
+                app.set_modal_error(format!(
+                    "A session named `{value}` already exists. Choose another name."
+                ));
+                return;
+            }
 
-fn handle_input_submission(app: &mut App) {
-    let Some(modal) = app.input_modal().cloned() else {
-        return;
-    };
+            let start_dir = env::current_dir()
+                .map(|dir| dir.display().to_string())
+                .unwrap_or_default();
+            app.pending_session = Some(value);
+            app.open_input_modal(InputIntent::SessionDirectory, start_dir);
+        }
+        InputIntent::SessionDirectory => {
+            let Some(name) = app.pending_session.clone() else {
+                app.close_modal();
+                app.set_warning_banner("Session name lost", "Press n to start the session again.");
+                return;
+            };
 
-    let value = modal.value.trim().to_string();
-    if let Some(error) = validate_name(&value) {
-        app.set_modal_error(error);
-        return;
-    }
+            let start_dir = if value.is_empty() {
+                None
+            } else {
+                let start_dir = util::expand_home(&value, env::var("HOME").ok().as_deref());
+                if !start_dir.is_dir() {
+                    app.set_modal_error(format!("`{value}` is not an existing directory."));
+                    return;
+                }
+                Some(start_dir.display().to_string())
+            };
 
-    match modal.intent {
-        InputIntent::NewSession => match tmux::create_session(&value) {
-            Ok(()) => {
-                app.close_modal();
-                app.focus = FocusArea::Sessions;
-                app.refresh_all();
-                app.select_session_by_name(&value);
-                app.set_success_banner(
-                    "Session created",
-                    format!("`{value}` is ready. Press Enter to attach or Tab to add a window."),
-                );
+            match tmux::create_session(&name, start_dir.as_deref(), Some(session_size_hint())) {
+                Ok(()) => {
+                    app.close_modal();
+                    app.pending_session = None;
+                    app.focus = FocusArea::Sessions;
+                    app.refresh_all();
+                    app.select_session_by_name(&name);
+                    app.set_success_banner(
+                        "Session created",
+                        match start_dir {
+                            Some(start_dir) => format!(
+                                "`{name}` is ready in `{start_dir}`. Press Enter to attach or Tab to add a window."
+                            ),
+                            None => format!(
+                                "`{name}` is ready. Press Enter to attach or Tab to add a window."
+                            ),
+                        },
+                    );
+                }
+                Err(err) => app.set_modal_error(format_user_error("Could not create session", err)),
             }
-            Err(err) => app.set_modal_error(format_user_error("Could not create session", err)),
-        },
+        }
         InputIntent::RenameSession => {
             let old_name = app
                 .get_selected_session()
                 .map(|session| session.name.clone());
-            let Some(old_name) = old_name else {
+            let session_id = app.get_selected_session().map(|session| session.id.clone());
+            let (Some(old_name), Some(session_id)) = (old_name, session_id) else {
                 app.close_modal();
                 app.set_warning_banner(
                     "Selection changed",
@@ -385,6 +2883,13 @@ fn handle_input_submission(app: &mut App) {
                 return;
             }
 
+            if app.session_name_taken(&value, Some(&session_id)) {
+                app.set_modal_error(format!(
+                    "A session named `{value}` already exists. Choose another name."
+                ));
+                return;
+            }
+
             match tmux::rename_session(&old_name, &value) {
                 Ok(()) => {
                     app.close_modal();
@@ -410,15 +2915,23 @@ fn handle_input_submission(app: &mut App) {
                 return;
             };
 
-            match tmux::create_window(&session_id, &value) {
+            let start_dir = app
+                .config
+                .inherit_path
+                .then(|| app.selected_path().map(str::to_string))
+                .flatten();
+            match tmux::create_window(&session_id, &value, start_dir.as_deref()) {
                 Ok(()) => {
                     app.close_modal();
                     app.focus = FocusArea::Windows;
                     app.refresh_all();
                     app.select_window_by_name(&value);
+                    let location = start_dir
+                        .map(|dir| format!(" in `{dir}`"))
+                        .unwrap_or_default();
                     app.set_success_banner(
                         "Window created",
-                        format!("`{value}` is ready. Press Tab to inspect its panes."),
+                        format!("`{value}` is ready{location}. Press Tab to inspect its panes."),
                     );
                 }
                 Err(err) => app.set_modal_error(format_user_error("Could not create window", err)),
@@ -445,6 +2958,13 @@ fn handle_input_submission(app: &mut App) {
                 return;
             }
 
+            if modal.warning.is_none() && app.window_name_taken(&value, Some(&window_id)) {
+                app.set_modal_warning(format!(
+                    "Another window in this session is already named `{value}`. Press Enter again to rename anyway."
+                ));
+                return;
+            }
+
             match tmux::rename_window(&window_id, &value) {
                 Ok(()) => {
                     app.close_modal();
@@ -468,6 +2988,11 @@ fn handle_confirmation(app: &mut App) {
     };
 
     match modal.intent {
+        ConfirmIntent::Attach => {
+            app.close_modal();
+            attach_selection(app);
+        }
+        ConfirmIntent::KillServer => app.open_input_modal(InputIntent::KillServer, ""),
         ConfirmIntent::Session => {
             let session_name = app
                 .get_selected_session()
@@ -481,8 +3006,15 @@ fn handle_confirmation(app: &mut App) {
                 return;
             };
 
+            if app.last_session_protected() {
+                app.set_modal_error("Safe mode keeps the last session. Press Esc to close.");
+                return;
+            }
+
+            let session_path = app.selected_session_path().unwrap_or_default().to_string();
             match tmux::kill_session(&session_name) {
                 Ok(()) => {
+                    app.remember_killed_session(session_name.clone(), session_path);
                     app.close_modal();
                     app.focus = FocusArea::Sessions;
                     app.refresh_all();
@@ -491,7 +3023,10 @@ fn handle_confirmation(app: &mut App) {
                     } else {
                         "The next available session is selected."
                     };
-                    app.set_success_banner("Session deleted", follow_up);
+                    app.set_success_banner(
+                        "Session deleted",
+                        format!("{follow_up} Press U to recreate it."),
+                    );
                 }
                 Err(err) => app.set_modal_error(format_user_error("Could not delete session", err)),
             }
@@ -558,13 +3093,279 @@ fn handle_confirmation(app: &mut App) {
     }
 }
 
+fn handle_picker_selection(app: &mut App) {
+    let Some(modal) = app.picker_modal().cloned() else {
+        return;
+    };
+    let Some(target) = modal.selected_index() else {
+        app.set_modal_error("Choose an item to continue.");
+        return;
+    };
+
+    match modal.intent {
+        PickerIntent::PanePosition => {
+            let pane_id = app.get_selected_pane().map(|pane| pane.id.clone());
+            let from = app.selected_pane_position();
+            let (Some(pane_id), Some(from)) = (pane_id, from) else {
+                app.close_modal();
+                app.set_warning_banner(
+                    "Selection changed",
+                    "Select a pane and try that move again.",
+                );
+                return;
+            };
+
+            if from == target {
+                app.close_modal();
+                app.set_info_banner("Pane unchanged", "The pane is already in that position.");
+                return;
+            }
+
+            let pane_ids: Vec<String> = app.panes.iter().map(|pane| pane.id.clone()).collect();
+            match tmux::move_pane(&pane_ids, from, target) {
+                Ok(()) => {
+                    app.close_modal();
+                    app.focus = FocusArea::Panes;
+                    app.refresh_all();
+                    app.set_success_banner(
+                        "Pane moved",
+                        format!("`{pane_id}` is now at position {target}."),
+                    );
+                }
+                Err(err) => app.set_modal_error(format_user_error("Could not move pane", err)),
+            }
+        }
+        PickerIntent::RecentlyKilled => {
+            let Some(killed) = app.recently_killed.get(target).cloned() else {
+                app.close_modal();
+                return;
+            };
+
+            if app.session_name_taken(&killed.name, None) {
+                app.set_modal_error(format!("A session named `{}` already exists.", killed.name));
+                return;
+            }
+
+            let start_dir = Some(killed.path.as_str()).filter(|path| !path.is_empty());
+            match tmux::create_session(&killed.name, start_dir, Some(session_size_hint())) {
+                Ok(()) => {
+                    app.recently_killed.remove(target);
+                    app.close_modal();
+                    app.focus = FocusArea::Sessions;
+                    app.refresh_all();
+                    app.select_session_by_name(&killed.name);
+                    app.set_success_banner(
+                        "Session recreated",
+                        format!(
+                            "`{}` is back as an empty session. Its old processes are gone.",
+                            killed.name
+                        ),
+                    );
+                }
+                Err(err) => {
+                    app.set_modal_error(format_user_error("Could not recreate session", err))
+                }
+            }
+        }
+        PickerIntent::Layouts => {
+            let Some(layout) = app::BUILTIN_LAYOUTS.get(target).copied() else {
+                app.close_modal();
+                return;
+            };
+            let Some(window) = app.get_selected_window().cloned() else {
+                app.set_modal_error("Select a window first; the layout is applied to it.");
+                return;
+            };
+
+            app.close_modal();
+            app.layout_cycle.insert(window.id.clone(), target);
+            apply_builtin_layout(app, &window, layout);
+        }
+        PickerIntent::LayoutPresets => {
+            let Some(preset) = app.layout_presets.get(target).cloned() else {
+                app.close_modal();
+                return;
+            };
+            let Some(window) = app.get_selected_window().cloned() else {
+                app.set_modal_error("Select a window first; the layout is applied to it.");
+                return;
+            };
+
+            match tmux::select_layout(&window.id, &preset.layout) {
+                Ok(()) => {
+                    app.close_modal();
+                    app.refresh_all();
+                    app.set_success_banner(
+                        "Layout applied",
+                        format!("`{}` now uses the `{}` layout.", window.name, preset.name),
+                    );
+                }
+                Err(err) => app.set_modal_error(format_user_error("Could not apply layout", err)),
+            }
+        }
+        PickerIntent::Buffers => {
+            let Some(buffer_name) = app.buffers.get(target).map(|buffer| buffer.name.clone())
+            else {
+                app.close_modal();
+                return;
+            };
+            let Some(pane_id) = app.get_selected_pane().map(|pane| pane.id.clone()) else {
+                app.set_modal_error("Select a pane first; the buffer is pasted into it.");
+                return;
+            };
+
+            match tmux::paste_buffer(&buffer_name, &pane_id) {
+                Ok(()) => {
+                    app.close_modal();
+                    app.set_success_banner(
+                        "Buffer pasted",
+                        format!("`{buffer_name}` was pasted into `{pane_id}`."),
+                    );
+                }
+                Err(err) => app.set_modal_error(format_user_error("Could not paste buffer", err)),
+            }
+        }
+        PickerIntent::Clients => {
+            let Some(client) = app.clients.get(target).cloned() else {
+                app.close_modal();
+                return;
+            };
+
+            match tmux::detach_client_tty(&client.tty) {
+                Ok(()) => {
+                    app.close_modal();
+                    app.refresh_all();
+                    app.set_success_banner(
+                        "Client detached",
+                        format!(
+                            "`{}` left `{}`; the session can size to its other clients again.",
+                            client.tty, client.session
+                        ),
+                    );
+                }
+                Err(err) => app.set_modal_error(format_user_error("Could not detach client", err)),
+            }
+        }
+        PickerIntent::Sockets => {
+            let Some(name) = app.sockets.get(target).cloned() else {
+                app.close_modal();
+                return;
+            };
+
+            app.close_modal();
+            app.switch_socket(Some(name.clone()));
+            if app.connection == tmux::TmuxConnectionState::Connected {
+                app.set_success_banner(
+                    "Server switched",
+                    format!("Showing the tmux server on socket `{name}`."),
+                );
+            }
+        }
+        PickerIntent::MergeSources => {
+            let Some(source) = app
+                .merge_sources()
+                .get(target)
+                .map(|session| (*session).clone())
+            else {
+                app.close_modal();
+                return;
+            };
+            let Some(destination) = app.get_selected_session().cloned() else {
+                app.set_modal_error("Select the session to merge into first.");
+                return;
+            };
+
+            match tmux::merge_sessions(&source.id, &destination.id) {
+                Ok(moved) => {
+                    app.close_modal();
+                    app.refresh_all();
+                    app.set_success_banner(
+                        "Sessions merged",
+                        format!(
+                            "Moved {moved} window(s) from `{}` into `{}`; tmux closed the empty `{}`.",
+                            source.name, destination.name, source.name
+                        ),
+                    );
+                }
+                Err(err) => {
+                    app.refresh_all();
+                    app.set_modal_error(format_user_error("Could not merge sessions", err));
+                }
+            }
+        }
+        PickerIntent::JoinSources => {
+            let Some(source) = app.join_sources.get(target).cloned() else {
+                app.close_modal();
+                return;
+            };
+            let Some(window_id) = app.get_selected_window().map(|window| window.id.clone()) else {
+                app.set_modal_error("Select the window to join into first.");
+                return;
+            };
+
+            match tmux::join_pane(&source.pane_id, &window_id) {
+                Ok(()) => {
+                    app.close_modal();
+                    app.refresh_all();
+                    app.set_success_banner(
+                        "Pane joined",
+                        format!(
+                            "`{}` moved here from `{}`.",
+                            source.pane_id, source.window_name
+                        ),
+                    );
+                }
+                Err(err) => app.set_modal_error(format_user_error("Could not join pane", err)),
+            }
+        }
+        PickerIntent::Hooks => {
+            let Some((hook, _)) = app::SESSION_HOOKS.get(target).copied() else {
+                app.close_modal();
+                return;
+            };
+
+            app.open_input_modal(InputIntent::SessionHook(hook), "");
+        }
+        PickerIntent::Actions => {
+            let Some(action) = app.menu_actions().get(target).copied() else {
+                app.close_modal();
+                return;
+            };
+            let (code, modifiers) = match action.key {
+                '\n' => (KeyCode::Enter, KeyModifiers::NONE),
+                '\u{8}' => (KeyCode::Backspace, KeyModifiers::NONE),
+                key @ '\u{1}'..='\u{1a}' => (
+                    KeyCode::Char((key as u8 + b'a' - 1) as char),
+                    KeyModifiers::CONTROL,
+                ),
+                key => (KeyCode::Char(key), KeyModifiers::NONE),
+            };
+
+            app.close_modal();
+            handle_normal_mode(app, code, modifiers);
+        }
+    }
+}
+
 fn handle_attach(app: &App) -> Result<()> {
     let Some(target) = app.target_attach.as_deref() else {
         return Ok(());
     };
 
-    if env::var("TMUX").is_ok() {
-        let status = Command::new("tmux")
+    if let Some((width, height)) = app.resized_to {
+        if let Err(err) = tmux::fit_window(target, width, height) {
+            eprintln!("tmuxui: {err:#}");
+        }
+    }
+
+    if let Some(command) = app.attach_command.as_deref() {
+        if let Err(err) = tmux::send_command(target, command) {
+            eprintln!("tmuxui: {err:#}");
+        }
+    }
+
+    if tmux::inside_selected_server() {
+        let status = tmux::command()
             .args(["switch-client", "-t", target])
             .status()
             .with_context(|| format!("could not switch to tmux session `{target}`"))?;
@@ -578,13 +3379,17 @@ fn handle_attach(app: &App) -> Result<()> {
 
     #[cfg(unix)]
     {
-        let err = Command::new("tmux").args(["attach", "-t", target]).exec();
+        let err = tmux::command()
+            .env_remove("TMUX")
+            .args(["attach", "-t", target])
+            .exec();
         Err(err).with_context(|| format!("could not attach to tmux session `{target}`"))
     }
 
     #[cfg(not(unix))]
     {
-        let status = Command::new("tmux")
+        let status = tmux::command()
+            .env_remove("TMUX")
             .args(["attach", "-t", target])
             .status()
             .with_context(|| format!("could not attach to tmux session `{target}`"))?;
@@ -597,16 +3402,41 @@ fn handle_attach(app: &App) -> Result<()> {
     }
 }
 
-fn validate_name(value: &str) -> Option<&'static str> {
-    if value.trim().is_empty() {
-        Some("Enter a name to continue.")
-    } else if value.contains('\n') || value.contains('\r') {
-        Some("Names cannot contain line breaks.")
-    } else {
-        None
+fn open_shell(path: &str) -> Result<()> {
+    let shell = env::var("SHELL")
+        .ok()
+        .filter(|shell| !shell.trim().is_empty())
+        .unwrap_or_else(|| "/bin/sh".to_string());
+
+    #[cfg(unix)]
+    {
+        let err = Command::new(&shell).current_dir(path).exec();
+        Err(err).with_context(|| format!("could not start `{shell}` in `{path}`"))
+    }
+
+    #[cfg(not(unix))]
+    {
+        let status = Command::new(&shell)
+            .current_dir(path)
+            .status()
+            .with_context(|| format!("could not start `{shell}` in `{path}`"))?;
+
+        if !status.success() {
+            bail!("{shell} exited with status {status}");
+        }
+
+        Ok(())
     }
 }
 
-fn format_user_error(action: &str, err: anyhow::Error) -> String {
-    format!("{action}: {err}")
+fn session_size_hint() -> (u16, u16) {
+    terminal::size()
+        .ok()
+        .filter(|(width, height)| *width > 0 && *height > 0)
+        .unwrap_or((200, 50))
+}
+
+fn format_user_error(action: &str, err: impl Into<anyhow::Error>) -> String {
+    let err = err.into();
+    format!("{action}: {err:#}")
 }
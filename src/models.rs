@@ -4,6 +4,10 @@ pub struct Session {
     pub name: String,
     pub window_count: usize,
     pub created: String,
+    pub created_at: i64,
+    pub last_activity: i64,
+    pub attached: usize,
+    pub destroy_unattached: bool,
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -12,14 +16,47 @@ pub struct Window {
     pub name: String,
     pub active: bool,
     pub layout: String,
+    pub path: String,
+    pub aggressive_resize: bool,
+    pub synchronized: bool,
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct Pane {
     pub id: String,
+    pub index: usize,
     pub width: u16,
     pub height: u16,
     pub current_path: String,
     pub current_command: String,
     pub active: bool,
+    pub zoomed: bool,
+    pub history_size: usize,
+    pub history_limit: usize,
+    pub title: String,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PaneLocation {
+    pub session_id: String,
+    pub session_name: String,
+    pub window_id: String,
+    pub window_name: String,
+    pub pane_id: String,
+    pub command: String,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Client {
+    pub tty: String,
+    pub session: String,
+    pub width: u16,
+    pub height: u16,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Buffer {
+    pub name: String,
+    pub size: usize,
+    pub sample: String,
 }
@@ -0,0 +1,46 @@
+use std::{env, ffi::OsString, path::PathBuf};
+
+const APP_DIR: &str = "tmuxui";
+
+fn config_dir() -> Option<PathBuf> {
+    config_base(env::var_os("XDG_CONFIG_HOME"), env::var_os("HOME")).map(|dir| dir.join(APP_DIR))
+}
+
+pub fn config_file() -> Option<PathBuf> {
+    config_dir().map(|dir| dir.join("config"))
+}
+
+pub fn layouts_file() -> Option<PathBuf> {
+    config_dir().map(|dir| dir.join("layouts"))
+}
+
+fn config_base(xdg: Option<OsString>, home: Option<OsString>) -> Option<PathBuf> {
+    xdg.map(PathBuf::from)
+        .filter(|dir| dir.is_absolute())
+        .or_else(|| {
+            home.filter(|home| !home.is_empty())
+                .map(|home| PathBuf::from(home).join(".config"))
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn prefers_absolute_xdg_dirs_and_falls_back_to_home() {
+        assert_eq!(
+            config_base(Some("/xdg/config".into()), Some("/home/me".into())),
+            Some(PathBuf::from("/xdg/config"))
+        );
+        assert_eq!(
+            config_base(Some("relative".into()), Some("/home/me".into())),
+            Some(PathBuf::from("/home/me/.config"))
+        );
+        assert_eq!(
+            config_base(Some("".into()), Some("/home/me".into())),
+            Some(PathBuf::from("/home/me/.config"))
+        );
+        assert_eq!(config_base(None, None), None);
+    }
+}
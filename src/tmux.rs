@@ -1,13 +1,26 @@
-use crate::models::{Pane, Session, Window};
-use anyhow::{anyhow, bail, Context, Result};
-use std::process::Command;
+use crate::config::SplitDirection;
+use crate::models::{Buffer, Client, Pane, PaneLocation, Session, Window};
+use anyhow::{bail, Context, Result};
+use std::{
+    env, fmt, fs, io,
+    path::{Path, PathBuf},
+    process::Command,
+    sync::RwLock,
+};
 
+pub const COMMAND_BUFFER: &str = "tmuxui-command";
+pub const TREE_BUFFER: &str = "tmuxui-tree";
+pub const PATH_BUFFER: &str = "tmuxui-path";
 const FIELD_SEPARATOR: char = '\u{1f}';
-const SESSION_FORMAT: &str =
-    "#{session_id}\u{1f}#{session_name}\u{1f}#{session_windows}\u{1f}#{session_created_string}";
-const WINDOW_FORMAT: &str =
-    "#{window_id}\u{1f}#{window_name}\u{1f}#{window_active}\u{1f}#{window_layout}";
-const PANE_FORMAT: &str = "#{pane_id}\u{1f}#{pane_width}\u{1f}#{pane_height}\u{1f}#{pane_current_path}\u{1f}#{pane_current_command}\u{1f}#{pane_active}";
+const SESSION_FORMAT: &str = "#{session_id}\u{1f}#{session_name}\u{1f}#{session_windows}\u{1f}#{session_created_string}\u{1f}#{session_created}\u{1f}#{session_attached}\u{1f}#{destroy-unattached}\u{1f}#{session_activity}";
+const WINDOW_FORMAT: &str = "#{window_id}\u{1f}#{window_name}\u{1f}#{window_active}\u{1f}#{window_layout}\u{1f}#{pane_current_path}\u{1f}#{aggressive-resize}\u{1f}#{synchronize-panes}";
+const PANE_FORMAT: &str = "#{pane_id}\u{1f}#{pane_width}\u{1f}#{pane_height}\u{1f}#{pane_current_path}\u{1f}#{pane_current_command}\u{1f}#{pane_active}\u{1f}#{history_size}\u{1f}#{history_limit}\u{1f}#{pane_index}\u{1f}#{&&:#{window_zoomed_flag},#{pane_active}}\u{1f}#{pane_title}";
+const PANE_LOCATION_FORMAT: &str = "#{session_id}\u{1f}#{session_name}\u{1f}#{window_id}\u{1f}#{window_name}\u{1f}#{pane_id}\u{1f}#{pane_current_command}";
+const RECENT_PANE_FORMAT: &str = "#{session_id}\u{1f}#{session_name}\u{1f}#{window_id}\u{1f}#{window_name}\u{1f}#{pane_id}\u{1f}#{pane_current_command}\u{1f}#{pane_last_active}\u{1f}#{window_active}\u{1f}#{pane_active}\u{1f}#{session_activity}";
+
+const CLIENT_FORMAT: &str =
+    "#{client_tty}\u{1f}#{client_session}\u{1f}#{client_width}\u{1f}#{client_height}";
+const BUFFER_FORMAT: &str = "#{buffer_name}\u{1f}#{buffer_size}\u{1f}#{buffer_sample}";
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum TmuxConnectionState {
@@ -24,29 +37,261 @@ pub struct SessionsSnapshot {
     pub detail: Option<String>,
 }
 
-pub fn run_tmux(args: &[&str]) -> Result<String> {
-    let output = Command::new("tmux")
-        .args(args)
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ResizeDirection {
+    Left,
+    Right,
+    Up,
+    Down,
+}
+
+impl ResizeDirection {
+    fn flag(self) -> &'static str {
+        match self {
+            Self::Left => "-L",
+            Self::Right => "-R",
+            Self::Up => "-U",
+            Self::Down => "-D",
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum TmuxFailure {
+    Spawn {
+        kind: io::ErrorKind,
+        message: String,
+    },
+    Exit {
+        status: Option<i32>,
+        stderr: String,
+    },
+}
+
+// clone_window, duplicate_window_to_session, merge_sessions and move_pane stay on
+// anyhow: besides tmux failures they reject unparseable tmux output and
+// out-of-range positions. The TmuxError of a failed command stays in their chain.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TmuxError {
+    pub command: String,
+    pub context: Option<String>,
+    pub failure: TmuxFailure,
+}
+
+impl TmuxError {
+    pub fn context(mut self, context: impl Into<String>) -> Self {
+        self.context = Some(context.into());
+        self
+    }
+
+    pub fn is_missing(&self) -> bool {
+        matches!(
+            self.failure,
+            TmuxFailure::Spawn {
+                kind: io::ErrorKind::NotFound,
+                ..
+            }
+        )
+    }
+
+    pub fn is_no_server(&self) -> bool {
+        match &self.failure {
+            TmuxFailure::Exit { stderr, .. } => is_no_server_error(stderr),
+            TmuxFailure::Spawn { .. } => false,
+        }
+    }
+}
+
+impl fmt::Display for TmuxError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(context) = &self.context {
+            write!(f, "{context}: ")?;
+        }
+        match &self.failure {
+            TmuxFailure::Spawn { message, .. } => write!(
+                f,
+                "failed to start tmux with args: {}: {message}",
+                self.command
+            ),
+            TmuxFailure::Exit { stderr, .. } if !stderr.is_empty() => f.write_str(stderr),
+            TmuxFailure::Exit {
+                status: Some(code), ..
+            } => write!(
+                f,
+                "tmux command `{}` failed with status {code}",
+                self.command
+            ),
+            TmuxFailure::Exit { status: None, .. } => {
+                write!(f, "tmux command `{}` was interrupted", self.command)
+            }
+        }
+    }
+}
+
+impl std::error::Error for TmuxError {}
+
+static SOCKET: RwLock<Option<String>> = RwLock::new(None);
+static BINARY: RwLock<Option<String>> = RwLock::new(None);
+
+pub fn set_binary(path: Option<String>) {
+    if let Ok(mut binary) = BINARY.write() {
+        *binary = path;
+    }
+}
+
+pub fn binary() -> String {
+    BINARY
+        .read()
+        .ok()
+        .and_then(|binary| binary.clone())
+        .unwrap_or_else(|| "tmux".to_string())
+}
+
+pub fn set_socket(name: Option<String>) {
+    if let Ok(mut socket) = SOCKET.write() {
+        *socket = name;
+    }
+}
+
+pub fn socket() -> Option<String> {
+    SOCKET.read().ok().and_then(|socket| socket.clone())
+}
+
+pub fn command() -> Command {
+    let mut command = Command::new(binary());
+    if let Some(socket) = socket() {
+        command.args([socket_flag(&socket), &socket]);
+    }
+    command
+}
+
+fn socket_flag(socket: &str) -> &'static str {
+    if socket.contains('/') {
+        "-S"
+    } else {
+        "-L"
+    }
+}
+
+pub fn inside_selected_server() -> bool {
+    let Ok(current) = env::var("TMUX") else {
+        return false;
+    };
+    match socket() {
+        Some(path) if path.contains('/') => {
+            socket_path_from_env(&current).map(Path::new) == Some(Path::new(&path))
+        }
+        Some(name) => socket_name_from_env(&current) == Some(name.as_str()),
+        None => true,
+    }
+}
+
+pub fn stale_tmux_env() -> Option<String> {
+    let current = env::var("TMUX").ok()?;
+    let Some(socket_path) = socket_path_from_env(&current) else {
+        return Some(format!(
+            "TMUX is set to `{current}`, which is not a tmux socket address."
+        ));
+    };
+
+    let reachable = command()
+        .args(["-S", socket_path, "list-sessions"])
         .output()
-        .with_context(|| format!("failed to start tmux with args: {}", args.join(" ")))?;
+        .map(|output| output.status.success())
+        .unwrap_or(false);
+    (!reachable)
+        .then(|| format!("TMUX points at `{socket_path}`, but no tmux server answers there."))
+}
+
+fn socket_path_from_env(tmux_env: &str) -> Option<&str> {
+    let mut parts = tmux_env.split(',');
+    let path = parts.next().filter(|path| !path.is_empty())?;
+    parts.next()?.parse::<u32>().ok()?;
+    parts.next()?.parse::<i64>().ok()?;
+    parts.next().is_none().then_some(path)
+}
+
+fn socket_name_from_env(tmux_env: &str) -> Option<&str> {
+    let path = tmux_env.split(',').next()?;
+    Path::new(path).file_name()?.to_str()
+}
+
+pub fn list_sockets() -> Result<Vec<String>> {
+    let dir = socket_dir()?;
+    let entries =
+        fs::read_dir(&dir).with_context(|| format!("could not read {}", dir.display()))?;
+
+    let mut names: Vec<String> = entries
+        .filter_map(|entry| entry.ok())
+        .filter(is_socket)
+        .filter_map(|entry| entry.file_name().into_string().ok())
+        .collect();
+    names.sort();
+    Ok(names)
+}
+
+fn socket_dir() -> Result<PathBuf> {
+    if let Ok(current) = env::var("TMUX") {
+        if let Some(dir) = current
+            .split(',')
+            .next()
+            .and_then(|path| Path::new(path).parent())
+        {
+            return Ok(dir.to_path_buf());
+        }
+    }
+
+    let output = Command::new("id")
+        .arg("-u")
+        .output()
+        .context("could not look up the user id")?;
+    let uid = String::from_utf8_lossy(&output.stdout).trim().to_string();
+    let base = env::var_os("TMUX_TMPDIR")
+        .filter(|dir| !dir.is_empty())
+        .map(PathBuf::from)
+        .unwrap_or_else(|| PathBuf::from("/tmp"));
+    Ok(base.join(format!("tmux-{uid}")))
+}
+
+#[cfg(unix)]
+fn is_socket(entry: &fs::DirEntry) -> bool {
+    use std::os::unix::fs::FileTypeExt;
+    entry
+        .file_type()
+        .map(|file_type| file_type.is_socket())
+        .unwrap_or(false)
+}
+
+#[cfg(not(unix))]
+fn is_socket(_entry: &fs::DirEntry) -> bool {
+    true
+}
+
+pub fn run_tmux(args: &[&str]) -> Result<String, TmuxError> {
+    let output = command().args(args).output().map_err(|err| TmuxError {
+        command: args.join(" "),
+        context: None,
+        failure: TmuxFailure::Spawn {
+            kind: err.kind(),
+            message: err.to_string(),
+        },
+    })?;
 
     if output.status.success() {
         return Ok(String::from_utf8_lossy(&output.stdout).trim().to_string());
     }
 
-    let stderr = String::from_utf8_lossy(&output.stderr).trim().to_string();
-    if stderr.is_empty() {
-        Err(anyhow!(
-            "tmux command `{}` failed with status {}",
-            args.join(" "),
-            output.status
-        ))
-    } else {
-        Err(anyhow!(stderr))
-    }
+    Err(TmuxError {
+        command: args.join(" "),
+        context: None,
+        failure: TmuxFailure::Exit {
+            status: output.status.code(),
+            stderr: String::from_utf8_lossy(&output.stderr).trim().to_string(),
+        },
+    })
 }
 
-fn run_tmux_unit(args: &[&str]) -> Result<()> {
+fn run_tmux_unit(args: &[&str]) -> Result<(), TmuxError> {
     run_tmux(args).map(|_| ())
 }
 
@@ -66,13 +311,13 @@ pub fn get_sessions_snapshot() -> SessionsSnapshot {
         },
         Err(err) => {
             let message = err.to_string();
-            if is_no_server_error(&message) {
+            if err.is_no_server() {
                 SessionsSnapshot {
                     sessions: Vec::new(),
                     connection: TmuxConnectionState::NoServer,
                     detail: None,
                 }
-            } else if is_tmux_missing_error(&message) {
+            } else if err.is_missing() {
                 SessionsSnapshot {
                     sessions: Vec::new(),
                     connection: TmuxConnectionState::Missing,
@@ -103,54 +348,752 @@ pub fn get_panes(window_id: &str) -> Result<Vec<Pane>> {
     parse_panes(&raw)
 }
 
-pub fn create_session(name: &str) -> Result<()> {
-    run_tmux_unit(&["new-session", "-d", "-s", name])
-        .with_context(|| format!("could not create session `{name}`"))
+pub fn session_tree(session: &Session) -> Result<String> {
+    let mut windows = Vec::new();
+    for window in get_windows(&session.id)? {
+        let panes = get_panes(&window.id)?;
+        windows.push((window, panes));
+    }
+    Ok(format_session_tree(session, &windows))
+}
+
+fn format_session_tree(session: &Session, windows: &[(Window, Vec<Pane>)]) -> String {
+    let mut lines = vec![session.name.clone()];
+    for (position, (window, panes)) in windows.iter().enumerate() {
+        let last_window = position + 1 == windows.len();
+        let (branch, indent) = if last_window {
+            ("└─", "   ")
+        } else {
+            ("├─", "│  ")
+        };
+        let active = if window.active { "*" } else { "" };
+        lines.push(format!("{branch} {}{active}  {}", window.name, window.path));
+
+        for (position, pane) in panes.iter().enumerate() {
+            let branch = if position + 1 == panes.len() {
+                "└─"
+            } else {
+                "├─"
+            };
+            let active = if pane.active { "*" } else { "" };
+            lines.push(format!(
+                "{indent}{branch} [{}]{active} {}  {}  {}x{}",
+                pane.index, pane.current_command, pane.current_path, pane.width, pane.height
+            ));
+        }
+    }
+    lines.join("\n")
+}
+
+pub fn find_panes_by_command(query: &str) -> Result<Vec<PaneLocation>> {
+    let raw = run_tmux(&["list-panes", "-a", "-F", PANE_LOCATION_FORMAT])
+        .context("could not list panes across the server")?;
+
+    Ok(matching_pane_locations(parse_pane_locations(&raw)?, query))
+}
+
+pub fn session_panes(session_id: &str) -> Result<Vec<PaneLocation>> {
+    let raw = run_tmux(&[
+        "list-panes",
+        "-s",
+        "-t",
+        session_id,
+        "-F",
+        PANE_LOCATION_FORMAT,
+    ])
+    .with_context(|| format!("could not list panes for session `{session_id}`"))?;
+
+    parse_pane_locations(&raw)
+}
+
+pub fn last_active_pane(exclude_pane: Option<&str>) -> Result<Option<PaneLocation>> {
+    let raw = run_tmux(&["list-panes", "-a", "-F", RECENT_PANE_FORMAT])
+        .context("could not list panes across the server")?;
+
+    most_recent_pane(&raw, exclude_pane)
+}
+
+fn most_recent_pane(raw: &str, exclude_pane: Option<&str>) -> Result<Option<PaneLocation>> {
+    let mut best: Option<(i64, PaneLocation)> = None;
+    for line in raw.lines().filter(|line| !line.trim().is_empty()) {
+        let parts = split_fields(line, 10, "recent pane")?;
+        if Some(parts[4]) == exclude_pane {
+            continue;
+        }
+
+        // Older tmux has no pane_last_active; use the session's last activity for the
+        // pane that is current in its session instead.
+        let last_active = parts[6].parse::<i64>().ok().or_else(|| {
+            (parts[7] == "1" && parts[8] == "1")
+                .then(|| parts[9].parse::<i64>().ok())
+                .flatten()
+        });
+        let Some(last_active) = last_active else {
+            continue;
+        };
+
+        if !matches!(&best, Some((time, _)) if *time >= last_active) {
+            best = Some((
+                last_active,
+                PaneLocation {
+                    session_id: parts[0].to_string(),
+                    session_name: parts[1].to_string(),
+                    window_id: parts[2].to_string(),
+                    window_name: parts[3].to_string(),
+                    pane_id: parts[4].to_string(),
+                    command: parts[5].to_string(),
+                },
+            ));
+        }
+    }
+    Ok(best.map(|(_, location)| location))
+}
+
+fn matching_pane_locations(locations: Vec<PaneLocation>, query: &str) -> Vec<PaneLocation> {
+    let query = query.to_lowercase();
+    locations
+        .into_iter()
+        .filter(|location| location.command.to_lowercase().contains(&query))
+        .collect()
+}
+
+pub fn get_buffers() -> Result<Vec<Buffer>> {
+    let raw = run_tmux(&["list-buffers", "-F", BUFFER_FORMAT])
+        .context("could not list tmux paste buffers")?;
+
+    parse_buffers(&raw)
+}
+
+pub fn list_clients() -> Result<Vec<Client>> {
+    let raw =
+        run_tmux(&["list-clients", "-F", CLIENT_FORMAT]).context("could not list tmux clients")?;
+
+    parse_clients(&raw)
+}
+
+pub fn detach_client_tty(tty: &str) -> Result<(), TmuxError> {
+    run_tmux_unit(&["detach-client", "-t", tty])
+        .map_err(|err| err.context(format!("could not detach client `{tty}`")))
+}
+
+pub fn paste_buffer(buffer_name: &str, pane_id: &str) -> Result<(), TmuxError> {
+    run_tmux_unit(&["paste-buffer", "-b", buffer_name, "-t", pane_id]).map_err(|err| {
+        err.context(format!(
+            "could not paste buffer `{buffer_name}` into pane `{pane_id}`"
+        ))
+    })
+}
+
+pub fn delete_buffer(buffer_name: &str) -> Result<(), TmuxError> {
+    run_tmux_unit(&["delete-buffer", "-b", buffer_name])
+        .map_err(|err| err.context(format!("could not delete buffer `{buffer_name}`")))
+}
+
+pub fn create_session(
+    name: &str,
+    start_dir: Option<&str>,
+    size: Option<(u16, u16)>,
+) -> Result<(), TmuxError> {
+    let args = new_session_args(name, start_dir, size);
+    let args: Vec<&str> = args.iter().map(String::as_str).collect();
+    run_tmux_unit(&args).map_err(|err| err.context(format!("could not create session `{name}`")))
+}
+
+pub fn create_dev_session(name: &str, size: Option<(u16, u16)>) -> Result<(), TmuxError> {
+    let mut args = new_session_args(name, None, size);
+    args.extend(["-P".to_string(), "-F".to_string(), "#{pane_id}".to_string()]);
+    let args: Vec<&str> = args.iter().map(String::as_str).collect();
+    let editor =
+        run_tmux(&args).map_err(|err| err.context(format!("could not create session `{name}`")))?;
+
+    run_tmux_unit(&["split-window", "-v", "-l", "30%", "-t", &editor])
+        .map_err(|err| err.context(format!("could not add the terminal pane to `{name}`")))?;
+    run_tmux_unit(&["split-window", "-h", "-l", "30%", "-t", &editor])
+        .map_err(|err| err.context(format!("could not add the side pane to `{name}`")))?;
+    run_tmux_unit(&["select-pane", "-t", &editor])
+        .map_err(|err| err.context(format!("could not focus the editor pane in `{name}`")))
+}
+
+fn new_session_args(name: &str, start_dir: Option<&str>, size: Option<(u16, u16)>) -> Vec<String> {
+    let mut args = vec![
+        "new-session".to_string(),
+        "-d".to_string(),
+        "-s".to_string(),
+        name.to_string(),
+    ];
+    if let Some(start_dir) = start_dir {
+        args.extend(["-c".to_string(), start_dir.to_string()]);
+    }
+    if let Some((width, height)) = size {
+        args.extend([
+            "-x".to_string(),
+            width.to_string(),
+            "-y".to_string(),
+            height.to_string(),
+        ]);
+    }
+    args
+}
+
+pub fn new_grouped_session(
+    source_id: &str,
+    name: &str,
+    size: Option<(u16, u16)>,
+) -> Result<(), TmuxError> {
+    let mut args = new_session_args(name, None, size);
+    args.extend(["-t".to_string(), source_id.to_string()]);
+    let args: Vec<&str> = args.iter().map(String::as_str).collect();
+    run_tmux_unit(&args)
+        .map_err(|err| err.context(format!("could not create grouped session `{name}`")))
+}
+
+pub fn rename_session(old_name: &str, new_name: &str) -> Result<(), TmuxError> {
+    run_tmux_unit(&["rename-session", "-t", old_name, new_name]).map_err(|err| {
+        err.context(format!(
+            "could not rename session `{old_name}` to `{new_name}`"
+        ))
+    })
 }
 
-pub fn rename_session(old_name: &str, new_name: &str) -> Result<()> {
-    run_tmux_unit(&["rename-session", "-t", old_name, new_name])
-        .with_context(|| format!("could not rename session `{old_name}` to `{new_name}`"))
+pub fn kill_server() -> Result<(), TmuxError> {
+    run_tmux_unit(&["kill-server"]).map_err(|err| err.context("could not stop the tmux server"))
 }
 
-pub fn kill_session(name: &str) -> Result<()> {
+pub fn kill_session(name: &str) -> Result<(), TmuxError> {
     run_tmux_unit(&["kill-session", "-t", name])
-        .with_context(|| format!("could not delete session `{name}`"))
+        .map_err(|err| err.context(format!("could not delete session `{name}`")))
 }
 
-pub fn create_window(session_id: &str, name: &str) -> Result<()> {
-    run_tmux_unit(&["new-window", "-t", session_id, "-n", name])
-        .with_context(|| format!("could not create window `{name}`"))
+pub fn create_window(
+    session_id: &str,
+    name: &str,
+    start_dir: Option<&str>,
+) -> Result<(), TmuxError> {
+    let mut args = vec!["new-window", "-t", session_id, "-n", name];
+    if let Some(start_dir) = start_dir {
+        args.extend(["-c", start_dir]);
+    }
+    run_tmux_unit(&args).map_err(|err| err.context(format!("could not create window `{name}`")))
+}
+
+pub fn create_window_in_path(session_id: &str, name: &str, path: &Path) -> Result<(), TmuxError> {
+    let path = path.to_string_lossy();
+    run_tmux_unit(&[
+        "new-window",
+        "-d",
+        "-t",
+        session_id,
+        "-n",
+        name,
+        "-c",
+        &path,
+    ])
+    .map_err(|err| err.context(format!("could not create window `{name}` in `{path}`")))
+}
+
+pub fn clone_window(window_id: &str) -> Result<String> {
+    let raw = run_tmux(&[
+        "display-message",
+        "-p",
+        "-t",
+        window_id,
+        "#{pane_current_path}\u{1f}#{pane_current_command}\u{1f}#{default-shell}",
+    ])
+    .with_context(|| format!("could not read the active pane of window `{window_id}`"))?;
+    let parts = split_fields(&raw, 3, "window clone source")?;
+
+    let args = clone_window_args(window_id, parts[0], parts[1], parts[2]);
+    let args: Vec<&str> = args.iter().map(String::as_str).collect();
+    run_tmux(&args).with_context(|| format!("could not clone window `{window_id}`"))
+}
+
+fn clone_window_args(window_id: &str, path: &str, command: &str, shell: &str) -> Vec<String> {
+    let mut args = vec![
+        "new-window".to_string(),
+        "-a".to_string(),
+        "-P".to_string(),
+        "-F".to_string(),
+        "#{window_id}".to_string(),
+        "-t".to_string(),
+        window_id.to_string(),
+    ];
+    if !path.is_empty() {
+        args.extend(["-c".to_string(), path.to_string()]);
+    }
+
+    if let Some(command) = pane_command(command, shell) {
+        args.push(command.to_string());
+    }
+    args
 }
 
-pub fn rename_window(window_id: &str, new_name: &str) -> Result<()> {
-    run_tmux_unit(&["rename-window", "-t", window_id, new_name])
-        .with_context(|| format!("could not rename window `{window_id}` to `{new_name}`"))
+fn pane_command<'a>(command: &'a str, shell: &str) -> Option<&'a str> {
+    let shell_name = shell.rsplit('/').next().unwrap_or(shell);
+    (!command.is_empty() && command != shell_name).then_some(command)
 }
 
-pub fn kill_window(window_id: &str) -> Result<()> {
+pub fn duplicate_window_to_session(
+    window: &Window,
+    session_name: &str,
+    size: Option<(u16, u16)>,
+) -> Result<()> {
+    let raw = run_tmux(&[
+        "list-panes",
+        "-t",
+        &window.id,
+        "-F",
+        "#{pane_current_path}\u{1f}#{pane_current_command}\u{1f}#{default-shell}",
+    ])
+    .with_context(|| format!("could not read the panes of window `{}`", window.id))?;
+    let panes = raw
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| split_fields(line, 3, "window duplicate source"))
+        .collect::<Result<Vec<_>>>()?;
+    let Some((first, rest)) = panes.split_first() else {
+        bail!("window `{}` has no panes to duplicate", window.id);
+    };
+
+    let start_dir = Some(first[0]).filter(|path| !path.is_empty());
+    let mut args = new_session_args(session_name, start_dir, size);
+    args.extend(["-n".to_string(), window.name.clone()]);
+    if let Some(command) = pane_command(first[1], first[2]) {
+        args.push(command.to_string());
+    }
+    let args: Vec<&str> = args.iter().map(String::as_str).collect();
+    run_tmux_unit(&args).with_context(|| format!("could not create session `{session_name}`"))?;
+
+    let target = format!("{session_name}:");
+    for pane in rest {
+        let mut args = vec!["split-window", "-t", &target];
+        if !pane[0].is_empty() {
+            args.extend(["-c", pane[0]]);
+        }
+        if let Some(command) = pane_command(pane[1], pane[2]) {
+            args.push(command);
+        }
+        run_tmux_unit(&args)
+            .with_context(|| format!("could not add a pane to session `{session_name}`"))?;
+    }
+
+    run_tmux_unit(&["select-layout", "-t", &target, &window.layout])
+        .with_context(|| format!("could not apply the layout of `{}`", window.name))
+}
+
+pub fn set_pane_title(pane_id: &str, title: &str) -> Result<(), TmuxError> {
+    run_tmux_unit(&["select-pane", "-t", pane_id, "-T", title]).map_err(|err| {
+        err.context(format!(
+            "could not set the title of pane `{pane_id}` to `{title}`"
+        ))
+    })
+}
+
+pub fn rename_window(window_id: &str, new_name: &str) -> Result<(), TmuxError> {
+    run_tmux_unit(&["rename-window", "-t", window_id, new_name]).map_err(|err| {
+        err.context(format!(
+            "could not rename window `{window_id}` to `{new_name}`"
+        ))
+    })
+}
+
+pub fn move_window(window_id: &str, target_session: &str) -> Result<(), TmuxError> {
+    run_tmux_unit(&[
+        "move-window",
+        "-d",
+        "-s",
+        window_id,
+        "-t",
+        &format!("{target_session}:"),
+    ])
+    .map_err(|err| {
+        err.context(format!(
+            "could not move window `{window_id}` to `{target_session}`"
+        ))
+    })
+}
+
+pub fn merge_sessions(src_id: &str, dst_id: &str) -> Result<usize> {
+    let windows = get_windows(src_id)?;
+    for window in &windows {
+        move_window(&window.id, dst_id)?;
+    }
+    Ok(windows.len())
+}
+
+pub fn swap_window(src_id: &str, dst_id: &str) -> Result<(), TmuxError> {
+    run_tmux_unit(&["swap-window", "-d", "-s", src_id, "-t", dst_id])
+        .map_err(|err| err.context(format!("could not swap window `{src_id}` with `{dst_id}`")))
+}
+
+pub fn kill_window(window_id: &str) -> Result<(), TmuxError> {
     run_tmux_unit(&["kill-window", "-t", window_id])
-        .with_context(|| format!("could not delete window `{window_id}`"))
+        .map_err(|err| err.context(format!("could not delete window `{window_id}`")))
 }
 
-pub fn select_window(window_id: &str) -> Result<()> {
-    run_tmux_unit(&["select-window", "-t", window_id])
-        .with_context(|| format!("could not select window `{window_id}`"))
+pub fn step_window(session_id: &str, forward: bool) -> Result<(), TmuxError> {
+    let command = if forward {
+        "next-window"
+    } else {
+        "previous-window"
+    };
+    run_tmux_unit(&[command, "-t", session_id])
+        .map_err(|err| err.context(format!("could not run {command} on `{session_id}`")))
 }
 
-pub fn create_pane(pane_id: &str) -> Result<()> {
-    run_tmux_unit(&["split-window", "-t", pane_id])
-        .with_context(|| format!("could not split pane `{pane_id}`"))
+pub fn last_window(session_id: &str) -> Result<(), TmuxError> {
+    run_tmux_unit(&["last-window", "-t", session_id]).map_err(|err| {
+        err.context(format!(
+            "could not switch `{session_id}` to its last window"
+        ))
+    })
 }
 
-pub fn kill_pane(pane_id: &str) -> Result<()> {
+pub fn attach_target(session_name: &str, window_id: Option<&str>, pane_id: Option<&str>) -> String {
+    match (window_id, pane_id) {
+        (Some(window_id), Some(pane_id)) => format!("{session_name}:{window_id}.{pane_id}"),
+        (Some(window_id), None) => format!("{session_name}:{window_id}"),
+        _ => session_name.to_string(),
+    }
+}
+
+pub fn fit_window(target: &str, width: u16, height: u16) -> Result<(), TmuxError> {
+    let had_local_size = !run_tmux(&["show-options", "-w", "-v", "-t", target, "window-size"])
+        .unwrap_or_default()
+        .is_empty();
+    let width = width.to_string();
+    let height = height.to_string();
+
+    run_tmux_unit(&["resize-window", "-t", target, "-x", &width, "-y", &height])
+        .map_err(|err| err.context(format!("could not resize `{target}` to {width}x{height}")))?;
+
+    if !had_local_size {
+        // resize-window pins the window to a manual size; hand sizing back to tmux.
+        run_tmux_unit(&["set-option", "-w", "-u", "-t", target, "window-size"]).map_err(|err| {
+            err.context(format!("could not restore automatic sizing for `{target}`"))
+        })?;
+    }
+
+    Ok(())
+}
+
+pub fn create_pane(
+    pane_id: &str,
+    direction: SplitDirection,
+    percent: u16,
+    start_dir: Option<&str>,
+) -> Result<(), TmuxError> {
+    let flag = match direction {
+        SplitDirection::Horizontal => "-h",
+        SplitDirection::Vertical => "-v",
+    };
+    let size = format!("{percent}%");
+    let mut args = vec!["split-window", flag, "-t", pane_id, "-l", &size];
+    if let Some(start_dir) = start_dir {
+        args.extend(["-c", start_dir]);
+    }
+    run_tmux_unit(&args).map_err(|err| err.context(format!("could not split pane `{pane_id}`")))
+}
+
+pub fn resize_pane(
+    pane_id: &str,
+    direction: ResizeDirection,
+    amount: u16,
+) -> Result<(), TmuxError> {
+    let amount = amount.to_string();
+    run_tmux_unit(&["resize-pane", "-t", pane_id, direction.flag(), &amount])
+        .map_err(|err| err.context(format!("could not resize pane `{pane_id}`")))
+}
+
+pub fn toggle_zoom(pane_id: &str) -> Result<(), TmuxError> {
+    run_tmux_unit(&["resize-pane", "-Z", "-t", pane_id])
+        .map_err(|err| err.context(format!("could not toggle zoom on pane `{pane_id}`")))
+}
+
+pub fn break_pane(pane_id: &str) -> Result<String, TmuxError> {
+    run_tmux(&[
+        "break-pane",
+        "-d",
+        "-P",
+        "-F",
+        "#{window_id}",
+        "-s",
+        pane_id,
+    ])
+    .map_err(|err| {
+        err.context(format!(
+            "could not break pane `{pane_id}` into its own window"
+        ))
+    })
+}
+
+pub fn join_pane(source_pane: &str, target_window: &str) -> Result<(), TmuxError> {
+    run_tmux_unit(&["join-pane", "-d", "-s", source_pane, "-t", target_window]).map_err(|err| {
+        err.context(format!(
+            "could not join pane `{source_pane}` into `{target_window}`"
+        ))
+    })
+}
+
+pub fn kill_pane(pane_id: &str) -> Result<(), TmuxError> {
     run_tmux_unit(&["kill-pane", "-t", pane_id])
-        .with_context(|| format!("could not delete pane `{pane_id}`"))
+        .map_err(|err| err.context(format!("could not delete pane `{pane_id}`")))
+}
+
+pub fn move_pane(pane_ids: &[String], from: usize, to: usize) -> Result<()> {
+    let Some(pane_id) = pane_ids.get(from) else {
+        bail!("pane position {from} is out of range");
+    };
+    if to >= pane_ids.len() {
+        bail!("pane position {to} is out of range");
+    }
+
+    let mut order: Vec<&str> = pane_ids.iter().map(String::as_str).collect();
+    let mut position = from;
+    while position != to {
+        let next = if to > position {
+            position + 1
+        } else {
+            position - 1
+        };
+        run_tmux_unit(&["swap-pane", "-d", "-s", order[position], "-t", order[next]])
+            .with_context(|| format!("could not move pane `{pane_id}` to position {to}"))?;
+        order.swap(position, next);
+        position = next;
+    }
+
+    Ok(())
+}
+
+pub fn make_main(pane_id: &str, window_id: &str) -> Result<(), TmuxError> {
+    let first_pane = run_tmux(&["list-panes", "-t", window_id, "-F", "#{pane_id}"])
+        .map_err(|err| err.context(format!("could not list panes for window `{window_id}`")))?
+        .lines()
+        .next()
+        .map(str::to_string);
+
+    if let Some(first_pane) = first_pane.filter(|first_pane| first_pane != pane_id) {
+        run_tmux_unit(&["swap-pane", "-d", "-s", pane_id, "-t", &first_pane]).map_err(|err| {
+            err.context(format!(
+                "could not move pane `{pane_id}` to the main position"
+            ))
+        })?;
+    }
+
+    run_tmux_unit(&["select-layout", "-t", window_id, "main-vertical"]).map_err(|err| {
+        err.context(format!(
+            "could not apply the main-vertical layout to `{window_id}`"
+        ))
+    })
+}
+
+pub fn capture_to_buffer(pane_id: &str, buffer_name: &str) -> Result<(), TmuxError> {
+    run_tmux_unit(&[
+        "capture-pane",
+        "-J",
+        "-S",
+        "-",
+        "-b",
+        buffer_name,
+        "-t",
+        pane_id,
+    ])
+    .map_err(|err| {
+        err.context(format!(
+            "could not copy pane `{pane_id}` into buffer `{buffer_name}`"
+        ))
+    })
+}
+
+pub fn scrollback_buffer_name(pane_id: &str, timestamp: u64) -> String {
+    format!("tmuxui-{}-{timestamp}", pane_id.trim_start_matches('%'))
+}
+
+pub fn send_command(target: &str, command: &str) -> Result<(), TmuxError> {
+    run_tmux_unit(&["send-keys", "-t", target, "-l", command])
+        .and_then(|()| run_tmux_unit(&["send-keys", "-t", target, "Enter"]))
+        .map_err(|err| err.context(format!("could not run `{command}` in `{target}`")))
+}
+
+pub fn mouse_enabled() -> Result<bool> {
+    let raw = run_tmux(&["show-options", "-g", "-v", "mouse"])
+        .context("could not read the global mouse option")?;
+    Ok(parse_on_off(&raw))
+}
+
+pub fn set_mouse(enabled: bool) -> Result<(), TmuxError> {
+    let value = if enabled { "on" } else { "off" };
+    run_tmux_unit(&["set-option", "-g", "mouse", value])
+        .map_err(|err| err.context(format!("could not turn the global mouse option {value}")))
+}
+
+pub fn toggle_status_position() -> Result<&'static str, TmuxError> {
+    let raw = run_tmux(&["show-options", "-g", "-v", "status-position"])
+        .map_err(|err| err.context("could not read the global status-position option"))?;
+    let position = next_status_position(&raw);
+    run_tmux_unit(&["set-option", "-g", "status-position", position])
+        .map_err(|err| err.context(format!("could not move the status bar to the {position}")))?;
+    Ok(position)
+}
+
+fn next_status_position(raw: &str) -> &'static str {
+    if raw.trim() == "top" {
+        "bottom"
+    } else {
+        "top"
+    }
+}
+
+fn parse_on_off(raw: &str) -> bool {
+    raw.trim() == "on"
+}
+
+pub fn set_aggressive_resize(window_id: &str, enabled: bool) -> Result<(), TmuxError> {
+    let value = if enabled { "on" } else { "off" };
+    run_tmux_unit(&[
+        "set-window-option",
+        "-t",
+        window_id,
+        "aggressive-resize",
+        value,
+    ])
+    .map_err(|err| {
+        err.context(format!(
+            "could not turn aggressive-resize {value} for `{window_id}`"
+        ))
+    })
+}
+
+pub fn set_synchronize_panes(window_id: &str, enabled: bool) -> Result<(), TmuxError> {
+    let value = if enabled { "on" } else { "off" };
+    run_tmux_unit(&[
+        "set-window-option",
+        "-t",
+        window_id,
+        "synchronize-panes",
+        value,
+    ])
+    .map_err(|err| {
+        err.context(format!(
+            "could not turn synchronize-panes {value} for `{window_id}`"
+        ))
+    })
+}
+
+pub fn set_destroy_unattached(session_id: &str, enabled: bool) -> Result<(), TmuxError> {
+    let value = if enabled { "on" } else { "off" };
+    run_tmux_unit(&["set-option", "-t", session_id, "destroy-unattached", value]).map_err(|err| {
+        err.context(format!(
+            "could not turn destroy-unattached {value} for `{session_id}`"
+        ))
+    })
+}
+
+pub fn set_session_hook(session_id: &str, hook: &str, command: &str) -> Result<(), TmuxError> {
+    run_tmux_unit(&["set-hook", "-t", session_id, hook, command])
+        .map_err(|err| err.context(format!("could not set the `{hook}` hook on `{session_id}`")))
+}
+
+pub fn detach_client() -> Result<(), TmuxError> {
+    run_tmux_unit(&["detach-client"]).map_err(|err| err.context("could not detach the tmux client"))
 }
 
-pub fn select_pane(pane_id: &str) -> Result<()> {
-    run_tmux_unit(&["select-pane", "-t", pane_id])
-        .with_context(|| format!("could not select pane `{pane_id}`"))
+pub fn display_panes() -> Result<(), TmuxError> {
+    run_tmux_unit(&["display-panes", "-b"])
+        .map_err(|err| err.context("could not display pane numbers"))
+}
+
+pub fn swap_panes(source: &str, target: &str) -> Result<(), TmuxError> {
+    run_tmux_unit(&["swap-pane", "-s", source, "-t", target])
+        .map_err(|err| err.context(format!("could not swap pane `{source}` with `{target}`")))
+}
+
+pub fn rotate_window(window_id: &str) -> Result<(), TmuxError> {
+    run_tmux_unit(&["rotate-window", "-t", window_id]).map_err(|err| {
+        err.context(format!(
+            "could not rotate the panes of window `{window_id}`"
+        ))
+    })
+}
+
+pub fn select_layout(window_id: &str, layout: &str) -> Result<(), TmuxError> {
+    run_tmux_unit(&["select-layout", "-t", window_id, layout]).map_err(|err| {
+        err.context(format!(
+            "could not apply layout `{layout}` to `{window_id}`"
+        ))
+    })
+}
+
+pub fn list_keys() -> Result<String> {
+    run_tmux(&["list-keys"]).context("could not list tmux key bindings")
+}
+
+pub fn own_session_id() -> Option<String> {
+    if !inside_selected_server() {
+        return None;
+    }
+    let pane_id = env::var("TMUX_PANE").ok()?;
+    run_tmux(&["display-message", "-p", "-t", &pane_id, "#{session_id}"])
+        .ok()
+        .map(|raw| raw.trim().to_string())
+        .filter(|id| !id.is_empty())
+}
+
+pub fn pane_start_command(pane_id: &str) -> Result<String> {
+    let raw = run_tmux(&[
+        "display-message",
+        "-p",
+        "-t",
+        pane_id,
+        "#{pane_start_command}",
+    ])
+    .with_context(|| format!("could not read the start command of pane `{pane_id}`"))?;
+    Ok(unquote_start_command(&raw))
+}
+
+fn unquote_start_command(raw: &str) -> String {
+    let command = raw.trim();
+    command
+        .strip_prefix('"')
+        .and_then(|inner| inner.strip_suffix('"'))
+        .filter(|inner| !inner.contains('"'))
+        .unwrap_or(command)
+        .to_string()
+}
+
+pub fn set_buffer(buffer_name: &str, content: &str) -> Result<(), TmuxError> {
+    run_tmux_unit(&["set-buffer", "-w", "-b", buffer_name, "--", content])
+        .map_err(|err| err.context(format!("could not write tmux buffer `{buffer_name}`")))
+}
+
+pub fn pane_is_idle(pane_id: &str) -> Result<bool> {
+    let raw = run_tmux(&[
+        "display-message",
+        "-p",
+        "-t",
+        pane_id,
+        "#{pane_current_command}\u{1f}#{default-shell}",
+    ])
+    .with_context(|| format!("could not read the command of pane `{pane_id}`"))?;
+    let parts = split_fields(&raw, 2, "pane command")?;
+    Ok(pane_command(parts[0], parts[1]).is_none())
+}
+
+pub fn select_next_pane(window_id: &str) -> Result<(), TmuxError> {
+    run_tmux_unit(&["select-pane", "-t", &format!("{window_id}.+")])
+        .map_err(|err| err.context(format!("could not activate the next pane in `{window_id}`")))
+}
+
+pub fn capture_pane(pane_id: &str) -> Result<String> {
+    run_tmux(&["capture-pane", "-p", "-t", pane_id])
+        .with_context(|| format!("could not capture pane `{pane_id}`"))
+}
+
+pub fn capture_pane_history(pane_id: &str, ansi: bool) -> Result<String> {
+    let mut args = vec!["capture-pane", "-p", "-J", "-S", "-", "-t", pane_id];
+    if ansi {
+        args.push("-e");
+    }
+    run_tmux(&args).with_context(|| format!("could not capture scrollback for pane `{pane_id}`"))
 }
 
 fn parse_sessions(raw: &str) -> Result<Vec<Session>> {
@@ -174,38 +1117,106 @@ fn parse_panes(raw: &str) -> Result<Vec<Pane>> {
         .collect()
 }
 
+fn parse_pane_locations(raw: &str) -> Result<Vec<PaneLocation>> {
+    raw.lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| {
+            let parts = split_fields(line, 6, "pane location")?;
+            Ok(PaneLocation {
+                session_id: parts[0].to_string(),
+                session_name: parts[1].to_string(),
+                window_id: parts[2].to_string(),
+                window_name: parts[3].to_string(),
+                pane_id: parts[4].to_string(),
+                command: parts[5].to_string(),
+            })
+        })
+        .collect()
+}
+
+fn parse_buffers(raw: &str) -> Result<Vec<Buffer>> {
+    raw.lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(parse_buffer_line)
+        .collect()
+}
+
+fn parse_clients(raw: &str) -> Result<Vec<Client>> {
+    raw.lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(parse_client_line)
+        .collect()
+}
+
 fn parse_session_line(line: &str) -> Result<Session> {
-    let parts = split_fields(line, 4, "session")?;
+    let parts = split_fields(line, 8, "session")?;
 
     Ok(Session {
         id: parts[0].to_string(),
         name: parts[1].to_string(),
-        window_count: parse_usize(parts[2], "session window count")?,
+        window_count: parts[2].trim().parse().unwrap_or(0),
         created: parts[3].to_string(),
+        created_at: parse_i64(parts[4], "session created time")?,
+        last_activity: parse_i64(parts[7], "session activity time")?,
+        attached: parse_usize(parts[5], "session attached count")?,
+        destroy_unattached: parse_option_enabled(parts[6]),
     })
 }
 
+fn parse_option_enabled(raw: &str) -> bool {
+    !matches!(raw.trim(), "" | "0" | "off")
+}
+
 fn parse_window_line(line: &str) -> Result<Window> {
-    let parts = split_fields(line, 4, "window")?;
+    let parts = split_fields(line, 7, "window")?;
 
     Ok(Window {
         id: parts[0].to_string(),
         name: parts[1].to_string(),
         active: parse_flag(parts[2], "window active")?,
         layout: parts[3].to_string(),
+        path: parts[4].to_string(),
+        aggressive_resize: parse_option_enabled(parts[5]),
+        synchronized: parse_option_enabled(parts[6]),
     })
 }
 
 fn parse_pane_line(line: &str) -> Result<Pane> {
-    let parts = split_fields(line, 6, "pane")?;
+    let parts = split_fields(line, 11, "pane")?;
 
     Ok(Pane {
         id: parts[0].to_string(),
+        index: parse_usize(parts[8], "pane index")?,
         width: parse_u16(parts[1], "pane width")?,
         height: parse_u16(parts[2], "pane height")?,
         current_path: parts[3].to_string(),
         current_command: parts[4].to_string(),
         active: parse_flag(parts[5], "pane active")?,
+        zoomed: parse_flag(parts[9], "pane zoomed")?,
+        history_size: parse_usize(parts[6], "pane history size")?,
+        history_limit: parse_usize(parts[7], "pane history limit")?,
+        title: parts[10].to_string(),
+    })
+}
+
+fn parse_buffer_line(line: &str) -> Result<Buffer> {
+    let parts = split_fields(line, 3, "buffer")?;
+
+    Ok(Buffer {
+        name: parts[0].to_string(),
+        size: parse_usize(parts[1], "buffer size")?,
+        sample: parts[2].to_string(),
+    })
+}
+
+fn parse_client_line(line: &str) -> Result<Client> {
+    let parts = split_fields(line, 4, "client")?;
+
+    Ok(Client {
+        tty: parts[0].to_string(),
+        session: parts[1].to_string(),
+        width: parse_u16(parts[2], "client width")?,
+        height: parse_u16(parts[3], "client height")?,
     })
 }
 
@@ -234,6 +1245,12 @@ fn parse_usize(value: &str, field_name: &str) -> Result<usize> {
         .with_context(|| format!("invalid {field_name} `{value}`"))
 }
 
+fn parse_i64(value: &str, field_name: &str) -> Result<i64> {
+    value
+        .parse()
+        .with_context(|| format!("invalid {field_name} `{value}`"))
+}
+
 fn parse_u16(value: &str, field_name: &str) -> Result<u16> {
     value
         .parse()
@@ -249,19 +1266,13 @@ fn is_no_server_error(message: &str) -> bool {
             && normalized.contains("no such file or directory"))
 }
 
-fn is_tmux_missing_error(message: &str) -> bool {
-    let normalized = message.to_ascii_lowercase();
-    normalized.contains("failed to start tmux")
-        && (normalized.contains("os error 2") || normalized.contains("no such file or directory"))
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
 
     #[test]
     fn parses_sessions_with_strong_types() {
-        let raw = "%0\u{1f}dev\u{1f}3\u{1f}Sun Apr 19 12:00:00 2026";
+        let raw = "%0\u{1f}dev\u{1f}3\u{1f}Sun Apr 19 12:00:00 2026\u{1f}1776600000\u{1f}2\u{1f}1\u{1f}1776603600";
 
         let sessions = parse_sessions(raw).expect("sessions should parse");
 
@@ -272,16 +1283,167 @@ mod tests {
                 name: "dev".to_string(),
                 window_count: 3,
                 created: "Sun Apr 19 12:00:00 2026".to_string(),
+                created_at: 1_776_600_000,
+                last_activity: 1_776_603_600,
+                attached: 2,
+                destroy_unattached: true,
+            }]
+        );
+    }
+
+    #[test]
+    fn unreadable_window_counts_default_to_zero() {
+        let sessions = parse_sessions("$1\u{1f}dev\u{1f}?\u{1f}-\u{1f}0\u{1f}0\u{1f}0\u{1f}0")
+            .expect("sessions should parse");
+
+        assert_eq!(sessions[0].window_count, 0);
+    }
+
+    #[test]
+    fn formats_a_session_as_an_indented_tree() {
+        let session = parse_sessions("$1\u{1f}dev\u{1f}2\u{1f}-\u{1f}0\u{1f}0\u{1f}0\u{1f}0")
+            .expect("sessions should parse")
+            .remove(0);
+        let windows = parse_windows(
+            "@1\u{1f}editor\u{1f}1\u{1f}tiled\u{1f}/srv/app\u{1f}0\u{1f}0\n@2\u{1f}logs\u{1f}0\u{1f}tiled\u{1f}/var/log\u{1f}0\u{1f}0",
+        )
+        .expect("windows should parse");
+        let editor_panes = parse_panes(
+            "%1\u{1f}120\u{1f}30\u{1f}/srv/app\u{1f}nvim\u{1f}1\u{1f}0\u{1f}0\u{1f}0\u{1f}0\u{1f}vm\n%2\u{1f}120\u{1f}10\u{1f}/srv/app\u{1f}zsh\u{1f}0\u{1f}0\u{1f}0\u{1f}1\u{1f}0\u{1f}vm",
+        )
+        .expect("panes should parse");
+        let log_panes = parse_panes(
+            "%3\u{1f}80\u{1f}24\u{1f}/var/log\u{1f}tail\u{1f}1\u{1f}0\u{1f}0\u{1f}0\u{1f}0\u{1f}vm",
+        )
+        .expect("panes should parse");
+
+        let tree = format_session_tree(
+            &session,
+            &[
+                (windows[0].clone(), editor_panes),
+                (windows[1].clone(), log_panes),
+            ],
+        );
+
+        assert_eq!(
+            tree,
+            "dev\n\
+             ├─ editor*  /srv/app\n\
+             │  ├─ [0]* nvim  /srv/app  120x30\n\
+             │  └─ [1] zsh  /srv/app  120x10\n\
+             └─ logs  /var/log\n   \
+             └─ [0]* tail  /var/log  80x24"
+        );
+    }
+
+    #[test]
+    fn picks_the_most_recently_active_pane() {
+        let raw = "$1\u{1f}dev\u{1f}@1\u{1f}editor\u{1f}%1\u{1f}nvim\u{1f}1700000300\u{1f}1\u{1f}1\u{1f}0\n\
+                   $1\u{1f}dev\u{1f}@1\u{1f}editor\u{1f}%2\u{1f}zsh\u{1f}1700000100\u{1f}1\u{1f}0\u{1f}0\n\
+                   $2\u{1f}ops\u{1f}@4\u{1f}logs\u{1f}%7\u{1f}tail\u{1f}1700000200\u{1f}1\u{1f}1\u{1f}0";
+
+        let pane = most_recent_pane(raw, None).unwrap().unwrap();
+        assert_eq!(
+            (pane.session_name.as_str(), pane.pane_id.as_str()),
+            ("dev", "%1")
+        );
+
+        let pane = most_recent_pane(raw, Some("%1")).unwrap().unwrap();
+        assert_eq!(pane.pane_id, "%7");
+    }
+
+    #[test]
+    fn falls_back_to_session_activity_without_pane_last_active() {
+        let raw = "$1\u{1f}dev\u{1f}@1\u{1f}editor\u{1f}%1\u{1f}nvim\u{1f}\u{1f}1\u{1f}1\u{1f}1700000100\n\
+                   $1\u{1f}dev\u{1f}@1\u{1f}editor\u{1f}%2\u{1f}zsh\u{1f}\u{1f}1\u{1f}0\u{1f}1700000100\n\
+                   $2\u{1f}ops\u{1f}@4\u{1f}logs\u{1f}%7\u{1f}tail\u{1f}\u{1f}1\u{1f}1\u{1f}1700000200";
+
+        let pane = most_recent_pane(raw, None).unwrap().unwrap();
+        assert_eq!(pane.pane_id, "%7");
+        assert_eq!(most_recent_pane("", None).unwrap(), None);
+    }
+
+    #[test]
+    fn parses_clients() {
+        let clients =
+            parse_clients("/dev/pts/3\u{1f}dev\u{1f}212\u{1f}54\n").expect("clients should parse");
+
+        assert_eq!(
+            clients,
+            vec![Client {
+                tty: "/dev/pts/3".to_string(),
+                session: "dev".to_string(),
+                width: 212,
+                height: 54,
+            }]
+        );
+    }
+
+    #[test]
+    fn parses_buffers_with_escaped_samples() {
+        let buffers =
+            parse_buffers("buffer0\u{1f}16\u{1f}line1\\nline2\\n\n").expect("buffers should parse");
+
+        assert_eq!(
+            buffers,
+            vec![Buffer {
+                name: "buffer0".to_string(),
+                size: 16,
+                sample: "line1\\nline2\\n".to_string(),
             }]
         );
     }
 
+    #[test]
+    fn resize_directions_map_to_tmux_flags() {
+        assert_eq!(ResizeDirection::Left.flag(), "-L");
+        assert_eq!(ResizeDirection::Right.flag(), "-R");
+        assert_eq!(ResizeDirection::Up.flag(), "-U");
+        assert_eq!(ResizeDirection::Down.flag(), "-D");
+    }
+
+    #[test]
+    fn clone_window_args_reuse_path_and_skip_the_default_shell() {
+        assert_eq!(
+            clone_window_args("@3", "/srv/app", "htop", "/bin/zsh"),
+            vec![
+                "new-window",
+                "-a",
+                "-P",
+                "-F",
+                "#{window_id}",
+                "-t",
+                "@3",
+                "-c",
+                "/srv/app",
+                "htop"
+            ]
+        );
+        assert_eq!(
+            clone_window_args("@3", "/srv/app", "zsh", "/bin/zsh"),
+            vec![
+                "new-window",
+                "-a",
+                "-P",
+                "-F",
+                "#{window_id}",
+                "-t",
+                "@3",
+                "-c",
+                "/srv/app"
+            ]
+        );
+    }
+
     #[test]
     fn parses_windows_and_panes() {
-        let windows = parse_windows("@1\u{1f}editor\u{1f}1\u{1f}main-vertical")
-            .expect("windows should parse");
-        let panes = parse_panes("%1\u{1f}120\u{1f}30\u{1f}/tmp\u{1f}zsh\u{1f}0")
-            .expect("panes should parse");
+        let windows =
+            parse_windows("@1\u{1f}editor\u{1f}1\u{1f}main-vertical\u{1f}/srv/app\u{1f}1\u{1f}on")
+                .expect("windows should parse");
+        let panes = parse_panes(
+            "%1\u{1f}120\u{1f}30\u{1f}/tmp\u{1f}zsh\u{1f}0\u{1f}5000\u{1f}50000\u{1f}1\u{1f}0\u{1f}api server",
+        )
+        .expect("panes should parse");
 
         assert_eq!(
             windows,
@@ -290,25 +1452,64 @@ mod tests {
                 name: "editor".to_string(),
                 active: true,
                 layout: "main-vertical".to_string(),
+                path: "/srv/app".to_string(),
+                aggressive_resize: true,
+                synchronized: true,
             }]
         );
         assert_eq!(
             panes,
             vec![Pane {
                 id: "%1".to_string(),
+                index: 1,
                 width: 120,
                 height: 30,
                 current_path: "/tmp".to_string(),
                 current_command: "zsh".to_string(),
                 active: false,
+                zoomed: false,
+                history_size: 5000,
+                history_limit: 50000,
+                title: "api server".to_string(),
             }]
         );
     }
 
+    #[test]
+    fn finds_panes_by_command_ignoring_case() {
+        let locations = parse_pane_locations(
+            "$1\u{1f}api\u{1f}@1\u{1f}server\u{1f}%1\u{1f}Node\n$1\u{1f}api\u{1f}@1\u{1f}server\u{1f}%2\u{1f}zsh\n$2\u{1f}web\u{1f}@4\u{1f}dev\u{1f}%7\u{1f}node\n",
+        )
+        .expect("locations should parse");
+
+        let matches = matching_pane_locations(locations, "node");
+
+        assert_eq!(
+            matches
+                .iter()
+                .map(|location| location.pane_id.as_str())
+                .collect::<Vec<_>>(),
+            vec!["%1", "%7"]
+        );
+        assert_eq!(matches[1].session_name, "web");
+    }
+
     #[test]
     fn rejects_malformed_tmux_output() {
         let err = parse_session_line("%0\u{1f}dev").expect_err("line should be rejected");
-        assert!(err.to_string().contains("expected 4 fields"));
+        assert!(err.to_string().contains("expected 8 fields"));
+    }
+
+    #[test]
+    fn new_session_passes_the_size_hint() {
+        assert_eq!(
+            new_session_args("dev", None, Some((200, 50))),
+            vec!["new-session", "-d", "-s", "dev", "-x", "200", "-y", "50"]
+        );
+        assert_eq!(
+            new_session_args("dev", Some("/srv/app"), None),
+            vec!["new-session", "-d", "-s", "dev", "-c", "/srv/app"]
+        );
     }
 
     #[test]
@@ -325,11 +1526,146 @@ mod tests {
 
     #[test]
     fn detects_missing_tmux_binary() {
-        assert!(is_tmux_missing_error(
+        let missing = TmuxError {
+            command: "list-sessions".to_string(),
+            context: None,
+            failure: TmuxFailure::Spawn {
+                kind: io::ErrorKind::NotFound,
+                message: "No such file or directory (os error 2)".to_string(),
+            },
+        };
+        assert!(missing.is_missing());
+        assert!(!missing.is_no_server());
+        assert_eq!(
+            missing.to_string(),
             "failed to start tmux with args: list-sessions: No such file or directory (os error 2)"
-        ));
-        assert!(!is_tmux_missing_error(
-            "error connecting to /tmp/tmux-501/default (No such file or directory)"
-        ));
+        );
+
+        let no_server = TmuxError {
+            command: "list-sessions".to_string(),
+            context: None,
+            failure: TmuxFailure::Exit {
+                status: Some(1),
+                stderr: "error connecting to /tmp/tmux-501/default (No such file or directory)"
+                    .to_string(),
+            },
+        };
+        assert!(!no_server.is_missing());
+        assert!(no_server.is_no_server());
+    }
+
+    #[test]
+    fn new_session_args_keep_spaced_names_in_one_argument() {
+        let args = new_session_args("my work", Some("/home/me/my project"), None);
+
+        assert_eq!(
+            args,
+            vec![
+                "new-session",
+                "-d",
+                "-s",
+                "my work",
+                "-c",
+                "/home/me/my project"
+            ]
+        );
+    }
+
+    #[test]
+    fn unquotes_single_argument_start_commands() {
+        assert_eq!(unquote_start_command("\"sleep 100\"\n"), "sleep 100");
+        assert_eq!(unquote_start_command("htop"), "htop");
+        assert_eq!(unquote_start_command("\"a\" \"b c\""), "\"a\" \"b c\"");
+        assert_eq!(unquote_start_command(""), "");
+    }
+
+    #[test]
+    fn tmux_errors_prefer_stderr_and_fall_back_to_the_status() {
+        let err = TmuxError {
+            command: "new-session -d -s dev".to_string(),
+            context: None,
+            failure: TmuxFailure::Exit {
+                status: Some(1),
+                stderr: "duplicate session: dev".to_string(),
+            },
+        };
+        assert_eq!(err.to_string(), "duplicate session: dev");
+        assert_eq!(
+            err.clone()
+                .context("could not create session `dev`")
+                .to_string(),
+            "could not create session `dev`: duplicate session: dev"
+        );
+
+        let silent = TmuxError {
+            failure: TmuxFailure::Exit {
+                status: Some(1),
+                stderr: String::new(),
+            },
+            ..err
+        };
+        assert_eq!(
+            silent.to_string(),
+            "tmux command `new-session -d -s dev` failed with status 1"
+        );
+    }
+
+    #[test]
+    fn reads_the_socket_name_from_the_tmux_variable() {
+        assert_eq!(
+            socket_name_from_env("/tmp/tmux-1000/work,4242,0"),
+            Some("work")
+        );
+        assert_eq!(socket_name_from_env(""), None);
+    }
+
+    #[test]
+    fn socket_paths_use_dash_s_and_names_dash_l() {
+        assert_eq!(socket_flag("work"), "-L");
+        assert_eq!(socket_flag("/run/user/1000/tmux-shared"), "-S");
+        assert_eq!(socket_flag("./local.sock"), "-S");
+    }
+
+    #[test]
+    fn validates_the_tmux_variable_shape() {
+        assert_eq!(
+            socket_path_from_env("/tmp/tmux-1000/default,4242,0"),
+            Some("/tmp/tmux-1000/default")
+        );
+        assert_eq!(socket_path_from_env("/tmp/tmux-1000/default"), None);
+        assert_eq!(socket_path_from_env(",4242,0"), None);
+        assert_eq!(socket_path_from_env("/tmp/x,pid,0"), None);
+        assert_eq!(socket_path_from_env("/tmp/x,1,0,extra"), None);
+    }
+
+    #[test]
+    fn parses_on_off_options() {
+        assert!(parse_on_off("on\n"));
+        assert!(!parse_on_off("off\n"));
+        assert!(!parse_on_off(""));
+    }
+
+    #[test]
+    fn status_position_flips_between_top_and_bottom() {
+        assert_eq!(next_status_position("top\n"), "bottom");
+        assert_eq!(next_status_position("bottom\n"), "top");
+    }
+
+    #[test]
+    fn attach_targets_are_as_precise_as_the_selection() {
+        assert_eq!(attach_target("work", None, None), "work");
+        assert_eq!(attach_target("work", Some("@2"), None), "work:@2");
+        assert_eq!(
+            attach_target("my work", Some("@2"), Some("%7")),
+            "my work:@2.%7"
+        );
+    }
+
+    #[test]
+    fn scrollback_buffer_names_include_pane_and_time() {
+        assert_eq!(
+            scrollback_buffer_name("%12", 1_776_600_000),
+            "tmuxui-12-1776600000"
+        );
     }
 }
@@ -1,6 +1,8 @@
 use crate::app::{
-    ActionAvailability, App, BannerTone, ConfirmIntent, FocusArea, InputIntent, ModalState,
+    describe_layout, ActionAvailability, App, BannerTone, ConfirmIntent, FocusArea, InputIntent,
+    ModalState, PanePreview, PickerIntent, SessionSort, SessionStateFilter,
 };
+use crate::config::{SplitDirection, TimeDisplay};
 use crate::tmux::TmuxConnectionState;
 use ratatui::{prelude::*, widgets::*};
 
@@ -12,6 +14,7 @@ pub enum LayoutMode {
 }
 
 pub fn draw(frame: &mut Frame, app: &mut App) {
+    app.panel_areas.clear();
     let size = frame.size();
     let layout_mode = layout_mode_for(size.width);
     let outer = Layout::default()
@@ -24,6 +27,11 @@ pub fn draw(frame: &mut Frame, app: &mut App) {
         ])
         .split(size);
 
+    if let ModalState::Pager(pager) = &mut app.modal {
+        let body = pager_body_rect(pager_rect(size));
+        pager.set_viewport_height(body.height as usize);
+    }
+
     frame.render_widget(Block::default().style(Style::default()), size);
     render_header(frame, outer[0], app, layout_mode);
     render_body(frame, outer[1], app, layout_mode);
@@ -37,6 +45,9 @@ pub fn draw(frame: &mut Frame, app: &mut App) {
     match &app.modal {
         ModalState::Input(modal) => render_input_modal(frame, app, modal),
         ModalState::Confirm(modal) => render_confirm_modal(frame, app, modal),
+        ModalState::Picker(modal) => render_picker_modal(frame, app, modal),
+        ModalState::Pager(pager) => render_pager(frame, app, pager),
+        ModalState::Split(modal) => render_split_modal(frame, modal),
         ModalState::None => {}
     }
 
@@ -57,9 +68,9 @@ pub fn layout_mode_for(width: u16) -> LayoutMode {
 
 fn render_header(frame: &mut Frame, area: Rect, app: &App, layout_mode: LayoutMode) {
     let focus_label = format!("Focus {}", app.focus.title());
-    let line1 = Line::from(vec![
+    let mut line1 = Line::from(vec![
         Span::styled(
-            " TMUXUI ",
+            format!(" {} ", app.header_title),
             Style::default()
                 .fg(Color::Black)
                 .bg(Color::Cyan)
@@ -88,6 +99,14 @@ fn render_header(frame: &mut Frame, area: Rect, app: &App, layout_mode: LayoutMo
             Style::default().fg(Color::White).bg(Color::DarkGray),
         ),
     ]);
+    if let Some(mouse) = app.mouse {
+        line1.spans.push(Span::raw(" "));
+        line1.spans.push(badge(
+            if mouse { "Mouse on" } else { "Mouse off" },
+            Style::default().fg(Color::Black).bg(Color::Gray),
+            Style::default().fg(Color::White).bg(Color::DarkGray),
+        ));
+    }
 
     let counts = format!(
         "{} sessions | {} windows | {} panes",
@@ -95,6 +114,10 @@ fn render_header(frame: &mut Frame, area: Rect, app: &App, layout_mode: LayoutMo
         app.windows.len(),
         app.panes.len()
     );
+    let counts = match app.socket.as_deref() {
+        Some(socket) => format!("Socket {socket} | {counts}"),
+        None => counts,
+    };
     let secondary = if let Some(filter) = app.filter_summary() {
         format!("{counts} | Filter {filter}")
     } else {
@@ -207,25 +230,57 @@ fn render_compact_context(frame: &mut Frame, area: Rect, app: &App) {
 }
 
 fn render_sessions_panel(frame: &mut Frame, area: Rect, app: &mut App) {
+    app.panel_areas.push((FocusArea::Sessions, area));
+    if app.focus == FocusArea::Sessions {
+        app.list_rows = area.height.saturating_sub(2) as usize;
+    }
     let visible = app.visible_session_indices();
+    let page_height = area.height.saturating_sub(2) as usize;
+    let selected = app.session_list_state.selected();
+    let start = page_start(
+        app.session_list_state.offset(),
+        selected,
+        page_height,
+        visible.len(),
+    );
     let items = if visible.is_empty() {
         vec![empty_item(session_empty_state(app))]
     } else {
         visible
             .iter()
+            .skip(start)
+            .take(page_height.max(1))
             .map(|index| {
                 let session = &app.sessions[*index];
+                let (primary, secondary) = if app.show_ids {
+                    (session.id.clone(), format!("  {}", session.name))
+                } else {
+                    (session.name.clone(), String::new())
+                };
                 ListItem::new(Line::from(vec![
                     Span::styled(
-                        session.name.clone(),
-                        Style::default().add_modifier(Modifier::BOLD),
+                        if session.attached > 0 { "● " } else { "  " },
+                        Style::default().fg(Color::Green),
+                    ),
+                    Span::styled(primary, Style::default().add_modifier(Modifier::BOLD)),
+                    Span::styled(secondary, Style::default().fg(Color::Gray)),
+                    Span::styled(
+                        if session.destroy_unattached {
+                            "  ephemeral"
+                        } else {
+                            ""
+                        },
+                        Style::default().fg(Color::Yellow),
                     ),
                     Span::styled(
-                        format!("  {} windows", session.window_count),
+                        match session.window_count {
+                            1 => "  1 window".to_string(),
+                            count => format!("  {count} windows"),
+                        },
                         Style::default().fg(Color::Gray),
                     ),
                     Span::styled(
-                        format!("  {}", compact_created(&session.created)),
+                        format!("  {}", compact_created(&app.session_created_label(session))),
                         Style::default().fg(Color::Gray),
                     ),
                 ]))
@@ -233,17 +288,48 @@ fn render_sessions_panel(frame: &mut Frame, area: Rect, app: &mut App) {
             .collect()
     };
 
+    let mut page_state =
+        ListState::default().with_selected(selected.map(|index| index.saturating_sub(start)));
     render_list_panel(
         frame,
         area,
         app.focus == FocusArea::Sessions,
-        format!("Sessions [{}]", visible.len()),
+        session_panel_title(app, visible.len()),
         items,
-        &mut app.session_list_state,
+        &mut page_state,
     );
+    *app.session_list_state.offset_mut() = start;
+}
+
+fn page_start(offset: usize, selected: Option<usize>, height: usize, len: usize) -> usize {
+    let height = height.max(1);
+    let mut start = offset.min(len.saturating_sub(height));
+    if let Some(selected) = selected {
+        if selected < start {
+            start = selected;
+        } else if selected >= start + height {
+            start = selected + 1 - height;
+        }
+    }
+    start
+}
+
+fn session_panel_title(app: &App, visible: usize) -> String {
+    let mut title = format!("Sessions [{visible}]");
+    if app.session_state_filter != SessionStateFilter::All {
+        title.push_str(&format!(" {}", app.session_state_filter.label()));
+    }
+    if app.session_sort != SessionSort::Name {
+        title.push_str(&format!(" by {}", app.session_sort.label()));
+    }
+    title
 }
 
 fn render_windows_panel(frame: &mut Frame, area: Rect, app: &mut App) {
+    app.panel_areas.push((FocusArea::Windows, area));
+    if app.focus == FocusArea::Windows {
+        app.list_rows = area.height.saturating_sub(2) as usize;
+    }
     let visible = app.visible_window_indices();
     let show_layout = area.width >= 28;
     let items = if visible.is_empty() {
@@ -253,16 +339,33 @@ fn render_windows_panel(frame: &mut Frame, area: Rect, app: &mut App) {
             .iter()
             .map(|index| {
                 let window = &app.windows[*index];
+                let (primary, secondary) = if app.show_ids {
+                    (&window.id, &window.name)
+                } else {
+                    (&window.name, &window.id)
+                };
                 let mut spans = vec![
                     Span::styled(
-                        format!("{} {}", if window.active { "*" } else { " " }, window.name),
+                        format!("{} {}", if window.active { "*" } else { " " }, primary),
                         Style::default().add_modifier(Modifier::BOLD),
                     ),
-                    Span::styled(format!("  {}", window.id), Style::default().fg(Color::Gray)),
+                    Span::styled(format!("  {secondary}"), Style::default().fg(Color::Gray)),
                 ];
+                if window.aggressive_resize {
+                    spans.push(Span::styled(
+                        "  aggressive",
+                        Style::default().fg(Color::Yellow),
+                    ));
+                }
+                if window.synchronized {
+                    spans.push(Span::styled(
+                        "  [SYNC]",
+                        Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
+                    ));
+                }
                 if show_layout {
                     spans.push(Span::styled(
-                        format!("  {}", window.layout),
+                        format!("  {}", describe_layout(&window.layout)),
                         Style::default().fg(Color::Gray),
                     ));
                 }
@@ -282,6 +385,10 @@ fn render_windows_panel(frame: &mut Frame, area: Rect, app: &mut App) {
 }
 
 fn render_panes_panel(frame: &mut Frame, area: Rect, app: &mut App) {
+    app.panel_areas.push((FocusArea::Panes, area));
+    if app.focus == FocusArea::Panes {
+        app.list_rows = area.height.saturating_sub(2) as usize;
+    }
     let visible = app.visible_pane_indices();
     let path_width = area.width.saturating_sub(10) as usize;
     let items = if visible.is_empty() {
@@ -291,18 +398,63 @@ fn render_panes_panel(frame: &mut Frame, area: Rect, app: &mut App) {
             .iter()
             .map(|index| {
                 let pane = &app.panes[*index];
+                let (label, secondary) = if app.show_ids {
+                    (pane.id.clone(), format!("[{}]", pane.index))
+                } else {
+                    (format!("[{}]", pane.index), pane.id.clone())
+                };
+                let mark = if app.is_pane_marked(&pane.id) {
+                    Span::styled(
+                        "  marked",
+                        Style::default()
+                            .fg(Color::Yellow)
+                            .add_modifier(Modifier::BOLD),
+                    )
+                } else {
+                    Span::raw("")
+                };
+                let watch = if app.is_pane_watched(&pane.id) {
+                    Span::styled("  watching", Style::default().fg(Color::Cyan))
+                } else {
+                    Span::raw("")
+                };
+                let title = match app.pane_title(pane) {
+                    Some(title) => {
+                        Span::styled(format!("  \"{title}\""), Style::default().fg(Color::Cyan))
+                    }
+                    None => Span::raw(""),
+                };
+                let zoom = if pane.zoomed {
+                    Span::styled("  [Z]", Style::default().fg(Color::Magenta))
+                } else {
+                    Span::raw("")
+                };
+                if app.compact_inactive_panes && !pane.active {
+                    return ListItem::new(Line::from(vec![
+                        Span::styled(
+                            format!("  {label} {}", pane.current_command),
+                            Style::default().fg(Color::Gray),
+                        ),
+                        mark,
+                        watch,
+                    ]));
+                }
                 ListItem::new(vec![
                     Line::from(vec![
                         Span::styled(
                             format!(
-                                "{} {}",
+                                "{} {label} {}",
                                 if pane.active { "*" } else { " " },
                                 pane.current_command
                             ),
                             Style::default().add_modifier(Modifier::BOLD),
                         ),
+                        title,
+                        zoom,
+                        mark,
+                        watch,
                         Span::styled(
-                            format!("  {}  {}x{}", pane.id, pane.width, pane.height),
+                            format!("  {secondary}  {}x{}", pane.width, pane.height),
                             Style::default().fg(Color::Gray),
                         ),
                     ]),
@@ -319,7 +471,11 @@ fn render_panes_panel(frame: &mut Frame, area: Rect, app: &mut App) {
         frame,
         area,
         app.focus == FocusArea::Panes,
-        format!("Panes [{}]", visible.len()),
+        if app.compact_inactive_panes {
+            format!("Panes [{}] compact", visible.len())
+        } else {
+            format!("Panes [{}]", visible.len())
+        },
         items,
         &mut app.pane_list_state,
     );
@@ -355,6 +511,11 @@ fn render_list_panel(
 }
 
 fn render_details_panel(frame: &mut Frame, area: Rect, app: &App) {
+    if let Some(preview) = &app.preview {
+        render_preview_panel(frame, area, preview);
+        return;
+    }
+
     let lines = selection_lines(app, app.action_availability(), area.width);
     frame.render_widget(
         Paragraph::new(lines)
@@ -369,6 +530,32 @@ fn render_details_panel(frame: &mut Frame, area: Rect, app: &App) {
     );
 }
 
+fn render_preview_panel(frame: &mut Frame, area: Rect, preview: &PanePreview) {
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title(match &preview.pane_id {
+            Some(pane_id) => format!(" Preview {pane_id} "),
+            None => " Preview ".to_string(),
+        })
+        .border_style(panel_border_style(false));
+    let height = block.inner(area).height as usize;
+    let lines: Vec<Line> = if let Some(error) = &preview.error {
+        vec![Line::styled(error.clone(), Style::default().fg(Color::Red))]
+    } else if preview.pane_id.is_none() {
+        vec![Line::styled(
+            "Select a pane to preview its screen.",
+            Style::default().fg(Color::Gray),
+        )]
+    } else {
+        preview.lines[preview.lines.len().saturating_sub(height)..]
+            .iter()
+            .map(|line| Line::raw(line.clone()))
+            .collect()
+    };
+
+    frame.render_widget(Paragraph::new(lines).block(block), area);
+}
+
 fn selection_lines(app: &App, actions: ActionAvailability, width: u16) -> Vec<Line<'static>> {
     let mut lines = Vec::new();
     match app.focus {
@@ -376,7 +563,11 @@ fn selection_lines(app: &App, actions: ActionAvailability, width: u16) -> Vec<Li
             if let Some(session) = app.get_selected_session() {
                 lines.push(detail_line("Name", session.name.clone()));
                 lines.push(detail_line("Id", session.id.clone()));
-                lines.push(detail_line("Started", compact_created(&session.created)));
+                let mut started = compact_created(&app.session_created_label(session));
+                if app.config.time_display == TimeDisplay::Utc {
+                    started.push_str(" UTC");
+                }
+                lines.push(detail_line("Started", started));
                 lines.push(detail_line("Windows", session.window_count.to_string()));
             } else {
                 lines.push(Line::styled(
@@ -393,7 +584,12 @@ fn selection_lines(app: &App, actions: ActionAvailability, width: u16) -> Vec<Li
             if let Some(window) = app.get_selected_window() {
                 lines.push(detail_line("Name", window.name.clone()));
                 lines.push(detail_line("Id", window.id.clone()));
-                lines.push(detail_line("Layout", window.layout.clone()));
+                lines.push(detail_line("Layout", describe_layout(&window.layout)));
+                lines.push(detail_line("Raw", window.layout.clone()));
+                lines.push(detail_line(
+                    "Path",
+                    truncate_middle(&window.path, width.saturating_sub(14) as usize),
+                ));
                 lines.push(detail_line(
                     "Active",
                     if window.active { "yes" } else { "no" }.to_string(),
@@ -412,6 +608,9 @@ fn selection_lines(app: &App, actions: ActionAvailability, width: u16) -> Vec<Li
         FocusArea::Panes => {
             if let Some(pane) = app.get_selected_pane() {
                 lines.push(detail_line("Command", pane.current_command.clone()));
+                if let Some(title) = app.pane_title(pane) {
+                    lines.push(detail_line("Title", title.to_string()));
+                }
                 lines.push(detail_line("Id", pane.id.clone()));
                 lines.push(detail_line(
                     "Size",
@@ -421,6 +620,10 @@ fn selection_lines(app: &App, actions: ActionAvailability, width: u16) -> Vec<Li
                     "Path",
                     truncate_middle(&pane.current_path, width.saturating_sub(14) as usize),
                 ));
+                lines.push(detail_line(
+                    "History",
+                    format!("{}/{}", pane.history_size, pane.history_limit),
+                ));
             } else {
                 lines.push(Line::styled(
                     "No pane is selected.",
@@ -524,7 +727,7 @@ fn render_filter_overlay(frame: &mut Frame, app: &App) {
     frame.render_widget(
         Paragraph::new(vec![
             Line::styled(
-                "Type to filter. Enter keeps it. Esc clears it.",
+                "Type to filter. Enter attaches, Tab keeps it, Esc clears it.",
                 Style::default().fg(Color::Gray),
             ),
             Line::raw(""),
@@ -566,31 +769,41 @@ fn render_input_modal(frame: &mut Frame, app: &App, modal: &crate::app::InputMod
         Paragraph::new(prompt).style(Style::default().fg(Color::Gray)),
         sections[0],
     );
+    let (visible_value, cursor) = input_view(
+        &modal.value,
+        modal.cursor,
+        sections[1].width.saturating_sub(2) as usize,
+    );
     frame.render_widget(
-        Paragraph::new(modal.value.as_str())
-            .block(
-                Block::default()
-                    .borders(Borders::ALL)
-                    .title(format!(" {field_label} "))
-                    .border_style(Style::default().fg(Color::White)),
-            )
-            .wrap(Wrap { trim: false }),
+        Paragraph::new(visible_value).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(format!(" {field_label} "))
+                .border_style(Style::default().fg(Color::White)),
+        ),
         sections[1],
     );
 
-    let feedback = modal
-        .error
-        .as_deref()
-        .map(|error| {
-            Line::styled(
-                error,
-                Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
-            )
-        })
-        .unwrap_or_else(|| Line::styled(submit_copy, Style::default().fg(Color::Gray)));
-    frame.render_widget(Paragraph::new(feedback), sections[2]);
+    let feedback = if let Some(error) = modal.error.as_deref() {
+        Line::styled(
+            error,
+            Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
+        )
+    } else if let Some(warning) = modal.warning.as_deref() {
+        Line::styled(
+            warning,
+            Style::default()
+                .fg(Color::Yellow)
+                .add_modifier(Modifier::BOLD),
+        )
+    } else {
+        Line::styled(submit_copy, Style::default().fg(Color::Gray))
+    };
+    frame.render_widget(
+        Paragraph::new(feedback).wrap(Wrap { trim: true }),
+        sections[2],
+    );
 
-    let cursor = visible_cursor(&modal.value, sections[1].width.saturating_sub(2) as usize);
     frame.set_cursor(sections[1].x + 1 + cursor as u16, sections[1].y + 1);
 }
 
@@ -604,6 +817,35 @@ fn render_confirm_modal(frame: &mut Frame, app: &App, modal: &crate::app::Confir
         .borders(Borders::ALL)
         .border_style(Style::default().fg(Color::Red));
     let inner = block.inner(area);
+    let inner = if modal.windows.is_empty() {
+        inner
+    } else {
+        let sections = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Min(3), Constraint::Length(5)])
+            .split(inner);
+        let windows: Vec<Line> = modal
+            .windows
+            .iter()
+            .skip(modal.scroll)
+            .map(|name| Line::raw(format!("  {name}")))
+            .collect();
+        let more = modal.windows.len() > 3;
+        frame.render_widget(
+            Paragraph::new(windows).block(
+                Block::default()
+                    .borders(Borders::TOP)
+                    .title(if more {
+                        format!(" Windows ({}) j/k scroll ", modal.windows.len())
+                    } else {
+                        format!(" Windows ({}) ", modal.windows.len())
+                    })
+                    .border_style(Style::default().fg(Color::Gray)),
+            ),
+            sections[1],
+        );
+        sections[0]
+    };
 
     frame.render_widget(block, area);
     frame.render_widget(
@@ -629,6 +871,292 @@ fn render_confirm_modal(frame: &mut Frame, app: &App, modal: &crate::app::Confir
     );
 }
 
+fn render_split_modal(frame: &mut Frame, modal: &crate::app::SplitModalState) {
+    let area = centered_rect(60, 60, frame.size());
+    frame.render_widget(Clear, area);
+
+    let block = Block::default()
+        .title(" Split Pane ")
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Yellow));
+    let inner = block.inner(area);
+    let sections = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(2),
+            Constraint::Min(4),
+            Constraint::Length(2),
+        ])
+        .split(inner);
+
+    frame.render_widget(block, area);
+
+    let (kept, new) = modal.sizes();
+    let summary = match modal.direction {
+        Some(SplitDirection::Horizontal) => format!(
+            "{} keeps {kept} columns. The new pane on the right gets {}% ({new} columns).",
+            modal.pane_id, modal.percent
+        ),
+        Some(SplitDirection::Vertical) => format!(
+            "{} keeps {kept} rows. The new pane below gets {}% ({new} rows).",
+            modal.pane_id, modal.percent
+        ),
+        None => format!(
+            "Press h to split {} side by side or v to split it top and bottom.",
+            modal.pane_id
+        ),
+    };
+    frame.render_widget(
+        Paragraph::new(summary)
+            .style(Style::default().fg(Color::Gray))
+            .wrap(Wrap { trim: true }),
+        sections[0],
+    );
+
+    let preview = Layout::default()
+        .direction(match modal.direction {
+            Some(SplitDirection::Horizontal) => Direction::Horizontal,
+            _ => Direction::Vertical,
+        })
+        .constraints([
+            Constraint::Percentage(100 - modal.percent),
+            Constraint::Percentage(modal.percent),
+        ])
+        .split(sections[1]);
+    frame.render_widget(
+        Block::default()
+            .title(format!(" {} ", modal.pane_id))
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Color::Gray)),
+        preview[0],
+    );
+    frame.render_widget(
+        Block::default()
+            .title(format!(" new {}% ", modal.percent))
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Color::Cyan))
+            .style(Style::default().bg(Color::DarkGray)),
+        preview[1],
+    );
+
+    let feedback = match modal.error.as_deref() {
+        Some(error) => Line::styled(
+            error,
+            Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
+        ),
+        None => Line::styled(
+            "h/v pick the direction and Up/Down the size. Press Enter to split, or Esc to cancel.",
+            Style::default().fg(Color::Gray),
+        ),
+    };
+    frame.render_widget(
+        Paragraph::new(feedback).wrap(Wrap { trim: true }),
+        sections[2],
+    );
+}
+
+fn render_picker_modal(frame: &mut Frame, app: &App, modal: &crate::app::PickerModalState) {
+    let area = centered_rect(60, 50, frame.size());
+    frame.render_widget(Clear, area);
+
+    let (title, prompt, submit_copy) = picker_modal_copy(app, modal.intent);
+    let block = Block::default()
+        .title(format!(" {title} "))
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Yellow));
+    let inner = block.inner(area);
+    let sections = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(2),
+            Constraint::Min(1),
+            Constraint::Length(1),
+        ])
+        .split(inner);
+
+    frame.render_widget(block, area);
+    frame.render_widget(
+        Paragraph::new(prompt)
+            .style(Style::default().fg(Color::Gray))
+            .wrap(Wrap { trim: true }),
+        sections[0],
+    );
+
+    let items: Vec<ListItem> = modal
+        .items
+        .iter()
+        .map(|item| {
+            ListItem::new(Line::from(vec![
+                Span::styled(
+                    item.label.clone(),
+                    Style::default().add_modifier(Modifier::BOLD),
+                ),
+                Span::styled(
+                    format!("  {}", item.detail),
+                    Style::default().fg(Color::Gray),
+                ),
+            ]))
+        })
+        .collect();
+    let mut list_state = modal.list_state.clone();
+    frame.render_stateful_widget(
+        List::new(items)
+            .highlight_style(Style::default().add_modifier(Modifier::REVERSED | Modifier::BOLD))
+            .highlight_symbol("> "),
+        sections[1],
+        &mut list_state,
+    );
+
+    let feedback = modal
+        .error
+        .as_deref()
+        .map(|error| {
+            Line::styled(
+                error,
+                Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
+            )
+        })
+        .unwrap_or_else(|| Line::styled(submit_copy, Style::default().fg(Color::Gray)));
+    frame.render_widget(Paragraph::new(feedback), sections[2]);
+}
+
+const PAGER_PANE_LIST_WIDTH: u16 = 22;
+
+fn pager_rect(size: Rect) -> Rect {
+    centered_rect(90, 86, size)
+}
+
+fn pager_body_rect(area: Rect) -> Rect {
+    let inner = Block::default().borders(Borders::ALL).inner(area);
+    Rect {
+        y: inner.y.saturating_add(1).min(inner.bottom()),
+        height: inner.height.saturating_sub(1),
+        ..inner
+    }
+}
+
+fn render_pager(frame: &mut Frame, app: &App, pager: &crate::app::PagerState) {
+    let area = pager_rect(frame.size());
+    frame.render_widget(Clear, area);
+
+    let block = Block::default()
+        .title(format!(" {} ", pager.title))
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Cyan));
+    let inner = block.inner(area);
+    frame.render_widget(block, area);
+
+    let status = if pager.searching || !pager.query.is_empty() {
+        let mut spans = vec![
+            Span::styled("Search ", Style::default().fg(Color::Gray)),
+            Span::styled(
+                pager.query.clone(),
+                Style::default().add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(
+                format!("  {} of {} lines", pager.visible.len(), pager.lines.len()),
+                Style::default().fg(Color::Gray),
+            ),
+        ];
+        if let Some(error) = pager.search_error.as_deref() {
+            spans.push(Span::styled(
+                format!("  {error}"),
+                Style::default().fg(Color::Yellow),
+            ));
+        }
+        Line::from(spans)
+    } else if !pager.preview_focused {
+        Line::styled(
+            "Choose a pane with j/k. Press Tab to scroll its scrollback.",
+            Style::default().fg(Color::Gray),
+        )
+    } else {
+        Line::styled(
+            format!(
+                "{} lines. Press / to search, Esc to close.",
+                pager.lines.len()
+            ),
+            Style::default().fg(Color::Gray),
+        )
+    };
+    frame.render_widget(
+        Paragraph::new(status),
+        Rect {
+            height: inner.height.min(1),
+            ..inner
+        },
+    );
+
+    let body: Vec<Line> = if pager.visible.is_empty() {
+        vec![Line::styled(
+            "No lines match the search.",
+            Style::default().fg(Color::Gray),
+        )]
+    } else {
+        match pager.ansi_lines.as_ref() {
+            Some(ansi_lines) => pager
+                .visible_line_indices()
+                .map(|index| crate::ansi::to_line(&ansi_lines[index]))
+                .collect(),
+            None => pager
+                .visible_lines()
+                .map(|line| Line::raw(line.to_string()))
+                .collect(),
+        }
+    };
+    let mut body_area = pager_body_rect(area);
+    if pager.pane_id.is_some() && body_area.width > PAGER_PANE_LIST_WIDTH * 2 {
+        let columns = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([
+                Constraint::Length(PAGER_PANE_LIST_WIDTH),
+                Constraint::Min(1),
+            ])
+            .split(body_area);
+        render_pager_pane_list(frame, columns[0], app, pager);
+        body_area = columns[1];
+    }
+    frame.render_widget(Paragraph::new(body), body_area);
+
+    if pager.searching {
+        let cursor_x = inner.x + 7 + pager.query.chars().count() as u16;
+        frame.set_cursor(cursor_x.min(inner.right().saturating_sub(1)), inner.y);
+    }
+}
+
+fn render_pager_pane_list(
+    frame: &mut Frame,
+    area: Rect,
+    app: &App,
+    pager: &crate::app::PagerState,
+) {
+    let block = Block::default()
+        .borders(Borders::RIGHT)
+        .border_style(panel_border_style(!pager.preview_focused));
+    let items: Vec<ListItem> = app
+        .panes
+        .iter()
+        .map(|pane| {
+            ListItem::new(truncate_middle(
+                &format!("[{}] {}", pane.index, pane.current_command),
+                area.width.saturating_sub(3) as usize,
+            ))
+        })
+        .collect();
+    let mut list_state = ListState::default().with_selected(
+        app.panes
+            .iter()
+            .position(|pane| Some(pane.id.as_str()) == pager.pane_id.as_deref()),
+    );
+    frame.render_stateful_widget(
+        List::new(items)
+            .block(block)
+            .highlight_style(Style::default().add_modifier(Modifier::REVERSED | Modifier::BOLD)),
+        area,
+        &mut list_state,
+    );
+}
+
 fn render_help_overlay(frame: &mut Frame) {
     let area = centered_rect(82, 78, frame.size());
     frame.render_widget(Clear, area);
@@ -644,23 +1172,87 @@ fn render_help_overlay(frame: &mut Frame) {
         Line::styled("Move", Style::default().add_modifier(Modifier::BOLD)),
         Line::raw("j/k or arrows move within a list."),
         Line::raw("Tab or h/l moves focus between sessions, windows, and panes."),
-        Line::raw("g/G jumps to the first or last visible row."),
+        Line::raw("Click a row to select it. Double-click attaches. The wheel scrolls a column."),
+        Line::raw("g/G jumps to the first or last visible row. Ctrl+D/Ctrl+U move half a page."),
+        Line::raw("H clears the filter and returns to the first session."),
+        Line::raw("A cycles the session list between all, attached, and detached."),
+        Line::raw("o on sessions sorts them by name, creation time, or recent activity."),
+        Line::raw("/ opens the quick filter for the current list."),
+        Line::raw("Type to filter, Up/Down pick a match, and Enter attaches to it."),
+        Line::raw("Tab keeps the filter and returns to the list. Esc clears it."),
         Line::raw(""),
         Line::styled("Work", Style::default().add_modifier(Modifier::BOLD)),
+        Line::raw("m lists the actions for the selected item. Enter runs one."),
         Line::raw("Enter attaches to the selected session, window, or pane."),
-        Line::raw("n creates a session or window, or splits the selected pane."),
-        Line::raw("R renames the selected session or window."),
-        Line::raw("d deletes the selected item after confirmation."),
-        Line::raw("/ opens the quick filter for the current list."),
-        Line::raw("Type to filter, Enter keeps it, and Esc clears it."),
+        Line::raw("a attaches and runs a command you type in the active pane."),
+        Line::raw("s types a command into the selected pane without attaching."),
+        Line::raw("! leaves tmuxui for a plain shell in the selected pane's directory."),
+        Line::raw("n creates a session or window, or previews a split of the selected pane."),
+        Line::raw(
+            "In the split preview, h splits side by side, v top and bottom, Up/Down size it.",
+        ),
+        Line::raw("N creates a session with an editor, terminal, and side pane (Sessions list)."),
+        Line::raw("R renames the selected session or window, or titles the selected pane."),
+        Line::raw("b offers the window's git branch as its new name (Windows list)."),
+        Line::raw("d deletes the selected item after confirmation, listing a session's windows."),
+        Line::raw("M on a window moves it into another session you name."),
+        Line::raw("K and J move the selected window up or down, swapping it with its neighbor."),
+        Line::raw("Backspace switches the session back to its last window, like prefix+l."),
+        Line::raw("] and [ switch the session to its next or previous window, like prefix+n/p."),
+        Line::raw("C clones the selected window with its directory and command."),
+        Line::raw("C on a session creates a session grouped with it that shares its windows."),
+        Line::raw("D copies the selected window's panes and commands into a new session."),
+        Line::raw("D on a session toggles destroy-unattached, shown as ephemeral."),
+        Line::raw("M on a session moves another session's windows into it, closing that one."),
+        Line::raw("e on a session sets a hook, like a command to run after-new-window."),
+        Line::raw("W adds a window per subdirectory of a folder you name, skipping hidden ones."),
+        Line::raw("A on a window toggles aggressive-resize, shown as aggressive."),
+        Line::raw("y on a window toggles synchronize-panes, shown as [SYNC]."),
+        Line::raw("t tiles the selected window's panes. Press it again to restore them."),
+        Line::raw("= makes the selected window's panes the same size."),
+        Line::raw("Ctrl+O rotates the selected window's panes one position, like prefix+C-o."),
+        Line::raw("w saves the selected window's layout. L applies a saved layout."),
+        Line::raw("V picks one of tmux's built-in layouts. Space on a window cycles them."),
+        Line::raw("U lists recently deleted sessions so you can recreate one."),
+        Line::raw("o makes the next pane in the window active in tmux and selects it."),
+        Line::raw("P moves the selected pane to a chosen position in its window."),
+        Line::raw("Space marks panes. x swaps two marked panes, or one with the selection."),
+        Line::raw("w on a pane watches its command and notifies you when it finishes."),
+        Line::raw("M makes the selected pane the main pane of a main-vertical layout."),
+        Line::raw("b on a pane moves it out into a new window in the same session."),
+        Line::raw("J on a pane pulls a pane from another window into this one."),
+        Line::raw("z zooms the selected pane to fill its window, marked [Z]. z again unzooms."),
+        Line::raw("< > - + move the selected pane's border left, right, up, or down."),
+        Line::raw("v opens the selected pane's scrollback. Type to keep matching lines."),
+        Line::raw("Y copies the selected pane's scrollback into a new tmux buffer."),
+        Line::raw("Y on a session copies its windows and panes as an indented text tree."),
+        Line::raw("| pipes the selected pane's scrollback into a shell command you type."),
+        Line::raw("e copies the command the selected pane runs to a buffer and the clipboard."),
+        Line::raw("c copies the selected pane's current directory the same way."),
+        Line::raw("B lists tmux paste buffers. Enter pastes into the selected pane, d deletes."),
+        Line::raw("I shows tmux ids instead of names, for matching list-sessions output."),
+        Line::raw("i shows only the command for inactive panes, for a denser list."),
+        Line::raw("# flashes pane numbers on the current tmux client."),
+        Line::raw("O turns tmux's global mouse option on or off for every session."),
+        Line::raw("T moves tmux's status bar between the top and bottom for every session."),
+        Line::raw("S lists tmux server sockets and switches tmuxui to the chosen one."),
+        Line::raw("X lists attached clients with their size. Enter detaches the chosen one."),
+        Line::raw(
+            "f finds the pane running a command anywhere on the server. F jumps to the next.",
+        ),
+        Line::raw("p swaps the selection details for a live preview of the selected pane."),
+        Line::raw("' attaches to the pane you were last working in, in any session."),
         Line::raw("r refreshes data from tmux."),
+        Line::raw("Ctrl+K stops the tmux server after you confirm and type yes."),
         Line::raw(""),
         Line::styled("Dialogs", Style::default().add_modifier(Modifier::BOLD)),
         Line::raw("Enter confirms. Esc cancels. Ctrl+U clears text while typing."),
+        Line::raw("Left/Right and Home/End move the cursor to edit text in the middle."),
         Line::raw(""),
         Line::styled("Leave", Style::default().add_modifier(Modifier::BOLD)),
         Line::raw("q or Esc closes tmuxui. Ctrl+C exits immediately."),
-        Line::raw("Press Esc, q, or ? to close this help panel."),
+        Line::raw("Q closes tmuxui and detaches the tmux client it runs in."),
+        Line::raw("Press b to search your tmux key bindings. Esc, q, or ? closes this panel."),
     ];
 
     frame.render_widget(Paragraph::new(help).wrap(Wrap { trim: true }), inner);
@@ -752,13 +1344,13 @@ fn truncate_middle(text: &str, width: usize) -> String {
     format!("{start}...{end}")
 }
 
-fn visible_cursor(value: &str, width: usize) -> usize {
-    let len = value.chars().count();
-    if len < width {
-        len
-    } else {
-        width
+fn input_view(value: &str, cursor: usize, width: usize) -> (String, usize) {
+    if width == 0 {
+        return (String::new(), 0);
     }
+    let start = cursor.saturating_sub(width - 1);
+    let visible = value.chars().skip(start).take(width).collect();
+    (visible, cursor - start)
 }
 
 fn session_empty_state(app: &App) -> String {
@@ -766,6 +1358,10 @@ fn session_empty_state(app: &App) -> String {
         TmuxConnectionState::NoServer => {
             "No tmux server is running. Press n to create your first session.".to_string()
         }
+        TmuxConnectionState::Missing if app.tmux_binary != "tmux" => format!(
+            "`{}` could not be run. Check the --tmux path, then restart tmuxui.",
+            app.tmux_binary
+        ),
         TmuxConnectionState::Missing => {
             "tmux is not installed. Install tmux, then restart tmuxui.".to_string()
         }
@@ -773,6 +1369,12 @@ fn session_empty_state(app: &App) -> String {
             "No sessions match \"{}\". Press / to edit the filter or Esc in the filter to clear it.",
             app.filter.query.trim()
         ),
+        _ if app.session_state_filter != SessionStateFilter::All && !app.sessions.is_empty() => {
+            format!(
+                "No {} sessions. Press A to show every session.",
+                app.session_state_filter.label()
+            )
+        }
         _ => "No sessions yet. Press n to create one.".to_string(),
     }
 }
@@ -814,7 +1416,23 @@ fn input_modal_copy(
             "New Session",
             "Create a tmux session from here without leaving the browser.".to_string(),
             "Session name",
-            format!("Press Enter to create session `{}`.", modal.value.trim()),
+            format!(
+                "Press Enter to choose where `{}` starts.",
+                modal.value.trim()
+            ),
+        ),
+        InputIntent::SessionDirectory => (
+            "New Session",
+            format!(
+                "Choose the directory `{}` starts in. Its first window and new panes open there.",
+                app.pending_session.as_deref().unwrap_or("the session")
+            ),
+            "Start directory",
+            if modal.value.trim().is_empty() {
+                "Press Enter to use tmux's default directory.".to_string()
+            } else {
+                format!("Press Enter to start the session in `{}`.", modal.value.trim())
+            },
         ),
         InputIntent::RenameSession => (
             "Rename Session",
@@ -831,11 +1449,18 @@ fn input_modal_copy(
         ),
         InputIntent::NewWindow => (
             "New Window",
-            format!(
-                "Create a new window inside {}.",
-                app.selected_session_name()
-                    .unwrap_or("the selected session")
-            ),
+            match app.selected_path().filter(|_| app.config.inherit_path) {
+                Some(path) => format!(
+                    "Create a new window inside {}, starting in {path}.",
+                    app.selected_session_name()
+                        .unwrap_or("the selected session")
+                ),
+                None => format!(
+                    "Create a new window inside {}.",
+                    app.selected_session_name()
+                        .unwrap_or("the selected session")
+                ),
+            },
             "Window name",
             format!("Press Enter to create window `{}`.", modal.value.trim()),
         ),
@@ -851,17 +1476,160 @@ fn input_modal_copy(
                 modal.value.trim()
             ),
         ),
-    }
-}
-
-fn confirm_modal_copy(app: &App, intent: ConfirmIntent) -> (&'static str, String, String, String) {
-    match intent {
+        InputIntent::SaveLayout => (
+            "Save Layout",
+            format!(
+                "Save the pane layout of {} so you can apply it to other windows.",
+                app.selected_window_name().unwrap_or("the selected window")
+            ),
+            "Preset name",
+            format!(
+                "Press Enter to save the layout as `{}`.",
+                modal.value.trim()
+            ),
+        ),
+        InputIntent::DevSession => (
+            "New Dev Session",
+            "Create a session with an editor pane, a terminal below it (30%), and a pane on the right."
+                .to_string(),
+            "Session name",
+            format!("Press Enter to create session `{}`.", modal.value.trim()),
+        ),
+        InputIntent::GroupedSession => (
+            "Grouped Session",
+            format!(
+                "Create a session grouped with {}. Both share the same windows: new, closed, or renamed windows show up in each, but each session can view a different one.",
+                app.selected_session_name().unwrap_or("the selected session")
+            ),
+            "Session name",
+            format!("Press Enter to create session `{}`.", modal.value.trim()),
+        ),
+        InputIntent::DuplicateWindow => (
+            "Duplicate Window",
+            format!(
+                "Copy {} with its panes, directories, and commands into a new session.",
+                app.selected_window_name().unwrap_or("the selected window")
+            ),
+            "Session name",
+            format!("Press Enter to create session `{}`.", modal.value.trim()),
+        ),
+        InputIntent::WindowsFromDirectory => (
+            "Windows From Directory",
+            format!(
+                "Add a window to {} for each subdirectory, starting in that directory. Hidden directories are skipped.",
+                app.selected_session_name().unwrap_or("the selected session")
+            ),
+            "Parent directory",
+            format!(
+                "Press Enter to create a window per folder in `{}`.",
+                modal.value.trim()
+            ),
+        ),
+        InputIntent::KillServer => (
+            "Stop tmux Server",
+            format!(
+                "This stops the tmux server and its {} session(s). It cannot be undone.",
+                app.sessions.len()
+            ),
+            "Type yes",
+            "Press Enter to run kill-server once the field says `yes`.".to_string(),
+        ),
+        InputIntent::PaneTitle => (
+            "Pane Title",
+            format!(
+                "Label {} so you can tell what it runs at a glance.",
+                app.selected_pane_id().unwrap_or("the selected pane")
+            ),
+            "Title",
+            format!("Press Enter to title the pane `{}`.", modal.value.trim()),
+        ),
+        InputIntent::PipeCommand => (
+            "Pipe Scrollback",
+            format!(
+                "Run a shell command with {}'s scrollback on its input, like `grep error` or `less`.",
+                app.selected_pane_id().unwrap_or("the selected pane")
+            ),
+            "Command",
+            format!("Press Enter to run `{}`.", modal.value.trim()),
+        ),
+        InputIntent::SendKeys => (
+            "Send Keys",
+            format!(
+                "Type text into {} and press Enter there, without attaching.",
+                app.selected_pane_id().unwrap_or("the selected pane")
+            ),
+            "Command",
+            format!("Press Enter to send `{}`.", modal.value.trim()),
+        ),
+        InputIntent::FindCommand => (
+            "Find Pane",
+            "Search every pane on the server for a running command, like `node` or `cargo`."
+                .to_string(),
+            "Command",
+            format!(
+                "Press Enter to jump to the first pane running `{}`.",
+                modal.value.trim()
+            ),
+        ),
+        InputIntent::MoveWindow => (
+            "Move Window",
+            format!(
+                "Move {} out of {} into another existing session.",
+                app.selected_window_name().unwrap_or("the selected window"),
+                app.selected_session_name().unwrap_or("its session")
+            ),
+            "Target session",
+            format!("Press Enter to move the window to `{}`.", modal.value.trim()),
+        ),
+        InputIntent::SessionHook(hook) => (
+            "Session Hook",
+            format!(
+                "Run a tmux command in {} on `{hook}`..",
+                app.selected_session_name().unwrap_or("the selected session")
+            ),
+            "tmux command",
+            format!("Press Enter to run `{}` on {hook}.", modal.value.trim()),
+        ),
+        InputIntent::AttachCommand => (
+            "Attach and Run",
+            format!(
+                "Attach to {} and run a command in its active pane.",
+                attach_target_label(app)
+            ),
+            "Command",
+            format!("Press Enter to attach and run `{}`.", modal.value.trim()),
+        ),
+    }
+}
+
+fn attach_target_label(app: &App) -> &str {
+    match app.focus {
+        FocusArea::Panes => app.selected_pane_id(),
+        FocusArea::Windows => app.selected_window_name(),
+        FocusArea::Sessions => None,
+    }
+    .or_else(|| app.selected_session_name())
+    .unwrap_or("the selected session")
+}
+
+fn confirm_modal_copy(app: &App, intent: ConfirmIntent) -> (&'static str, String, String, String) {
+    match intent {
         ConfirmIntent::Session => {
             let target = app.selected_session_name().unwrap_or("No session selected");
             let window_count = app
                 .get_selected_session()
                 .map(|session| session.window_count)
                 .unwrap_or(0);
+            if app.last_session_protected() {
+                return (
+                    "Delete Session",
+                    target.to_string(),
+                    "Safe mode: this is the last session, and deleting it stops the tmux server."
+                        .to_string(),
+                    "Set `safe_mode = off` in the config to allow it. Press Esc to keep it."
+                        .to_string(),
+                );
+            }
             (
                 "Delete Session",
                 target.to_string(),
@@ -877,6 +1645,29 @@ fn confirm_modal_copy(app: &App, intent: ConfirmIntent) -> (&'static str, String
             "This permanently removes the selected window.".to_string(),
             "Press Enter to delete the window, or Esc to keep it.".to_string(),
         ),
+        ConfirmIntent::Attach => {
+            let clients = app.other_attached_clients();
+            (
+                "Attach Session",
+                attach_target_label(app).to_string(),
+                format!(
+                    "{} already has {clients} other client(s) attached; they will see what you do and may be resized.",
+                    app.selected_session_name().unwrap_or("The session")
+                ),
+                "Press Enter to attach anyway, or Esc to stay here.".to_string(),
+            )
+        }
+        ConfirmIntent::KillServer => (
+            "Stop tmux Server",
+            format!("{} session(s)", app.sessions.len()),
+            if app.own_session_id.is_some() {
+                "kill-server closes every session, window, and pane, including the one tmuxui runs in."
+                    .to_string()
+            } else {
+                "kill-server closes every session, window, and pane on this server.".to_string()
+            },
+            "Press Enter to continue to the final step, or Esc to keep the server.".to_string(),
+        ),
         ConfirmIntent::Pane => (
             "Delete Pane",
             app.selected_pane_id()
@@ -888,15 +1679,159 @@ fn confirm_modal_copy(app: &App, intent: ConfirmIntent) -> (&'static str, String
     }
 }
 
+fn picker_modal_copy(app: &App, intent: PickerIntent) -> (&'static str, String, String) {
+    match intent {
+        PickerIntent::PanePosition => (
+            "Move Pane",
+            format!(
+                "Choose where {} should sit in {}. Other panes keep their order.",
+                app.selected_pane_id().unwrap_or("the selected pane"),
+                app.selected_window_name().unwrap_or("the selected window")
+            ),
+            "Press Enter to move the pane there, or Esc to keep it.".to_string(),
+        ),
+        PickerIntent::RecentlyKilled => (
+            "Recently Deleted",
+            "Recreate a session you deleted here. It starts empty in the same directory."
+                .to_string(),
+            "Press Enter to recreate the session, or Esc to close.".to_string(),
+        ),
+        PickerIntent::Layouts => (
+            "Layouts",
+            format!(
+                "Rearrange the panes of {} with one of tmux's built-in layouts.",
+                app.selected_window_name().unwrap_or("the selected window")
+            ),
+            "Press Enter to apply, or Esc to close. Space in the Windows list cycles them."
+                .to_string(),
+        ),
+        PickerIntent::LayoutPresets => (
+            "Saved Layouts",
+            format!(
+                "Apply a saved layout to {}. Layouts need the same number of panes.",
+                app.selected_window_name().unwrap_or("the selected window")
+            ),
+            "Press Enter to apply, d to delete the preset, or Esc to close.".to_string(),
+        ),
+        PickerIntent::Buffers => (
+            "Paste Buffers",
+            format!(
+                "tmux paste buffers, newest first. Enter pastes into {}.",
+                app.selected_pane_id().unwrap_or("the selected pane")
+            ),
+            "Press Enter to paste, d to delete the buffer, or Esc to close.".to_string(),
+        ),
+        PickerIntent::Clients => (
+            "Clients",
+            "Terminals attached to this server, with their session and size. A small client can hold a session to its size."
+                .to_string(),
+            "Press Enter to detach the chosen client, or Esc to close.".to_string(),
+        ),
+        PickerIntent::Sockets => (
+            "tmux Servers",
+            "Sockets in your tmux socket directory. Everything reloads from the chosen server."
+                .to_string(),
+            "Press Enter to switch servers, or Esc to close.".to_string(),
+        ),
+        PickerIntent::MergeSources => (
+            "Merge Sessions",
+            format!(
+                "Move every window of the chosen session into {}. The emptied session closes.",
+                app.selected_session_name()
+                    .unwrap_or("the selected session")
+            ),
+            "Press Enter to merge, or Esc to close.".to_string(),
+        ),
+        PickerIntent::JoinSources => (
+            "Join Pane",
+            format!(
+                "Move a pane from another window of this session into {}.",
+                app.selected_window_name().unwrap_or("the selected window")
+            ),
+            "Press Enter to join the pane, or Esc to close.".to_string(),
+        ),
+        PickerIntent::Hooks => (
+            "Session Hooks",
+            format!(
+                "Choose when tmux should run a command for {}.",
+                app.selected_session_name()
+                    .unwrap_or("the selected session")
+            ),
+            "Press Enter to type the command, or Esc to close.".to_string(),
+        ),
+        PickerIntent::Actions => (
+            "Actions",
+            format!(
+                "Actions for the selected {}. Each one also has the key shown on the left.",
+                match app.focus {
+                    FocusArea::Sessions => "session",
+                    FocusArea::Windows => "window",
+                    FocusArea::Panes => "pane",
+                }
+            ),
+            "Press Enter to run the action, or Esc to close.".to_string(),
+        ),
+    }
+}
+
 fn shortcuts(app: &App) -> String {
     if app.help.visible {
         "Esc close help".to_string()
     } else if app.filter.active {
-        "Type to filter  Enter keep  Esc clear  Ctrl+U reset".to_string()
+        "Type to filter  Up/Down move  Enter attach  Tab keep  Esc clear  Ctrl+U reset".to_string()
     } else {
         match app.modal {
+            ModalState::Input(ref modal) if modal.intent == InputIntent::AttachCommand => {
+                "Type a command  Enter attach  Esc cancel  Ctrl+U clear".to_string()
+            }
+            ModalState::Input(ref modal) if modal.intent == InputIntent::PipeCommand => {
+                "Type a command  Enter run  Esc cancel  Ctrl+U clear".to_string()
+            }
+            ModalState::Input(ref modal) if modal.intent == InputIntent::SendKeys => {
+                "Type a command  Enter send  Esc cancel  Ctrl+U clear".to_string()
+            }
+            ModalState::Input(ref modal)
+                if matches!(
+                    modal.intent,
+                    InputIntent::WindowsFromDirectory | InputIntent::SessionDirectory
+                ) =>
+            {
+                "Type a directory  Enter create  Esc cancel  Ctrl+U clear".to_string()
+            }
+            ModalState::Input(ref modal) if modal.intent == InputIntent::KillServer => {
+                "Type yes  Enter stop the server  Esc cancel".to_string()
+            }
+            ModalState::Input(ref modal) if modal.intent == InputIntent::FindCommand => {
+                "Type a command  Enter find  Esc cancel  Ctrl+U clear".to_string()
+            }
+            ModalState::Input(ref modal) if matches!(modal.intent, InputIntent::SessionHook(_)) => {
+                "Type a tmux command  Enter save  Esc cancel  Ctrl+U clear".to_string()
+            }
             ModalState::Input(_) => "Type a name  Enter save  Esc cancel  Ctrl+U clear".to_string(),
             ModalState::Confirm(_) => "Enter confirm  Esc cancel".to_string(),
+            ModalState::Picker(ref modal) if modal.intent == PickerIntent::Buffers => {
+                "j/k choose  Enter paste  d delete  Esc close".to_string()
+            }
+            ModalState::Picker(ref modal) if modal.intent == PickerIntent::LayoutPresets => {
+                "j/k choose  Enter apply  d delete  Esc close".to_string()
+            }
+            ModalState::Picker(ref modal) if modal.intent == PickerIntent::Actions => {
+                "j/k choose  Enter run  Esc close".to_string()
+            }
+            ModalState::Picker(_) => "j/k choose  Enter apply  Esc cancel".to_string(),
+            ModalState::Pager(ref pager) if pager.searching => {
+                "Type to search  Enter keep  Esc clear  Ctrl+U reset".to_string()
+            }
+            ModalState::Pager(ref pager) if !pager.preview_focused => {
+                "j/k choose pane  Tab scrollback  Esc close".to_string()
+            }
+            ModalState::Pager(ref pager) if pager.pane_id.is_some() => {
+                "j/k scroll  g/G ends  / search  e colors  Tab panes  Esc close".to_string()
+            }
+            ModalState::Pager(_) => "j/k scroll  g/G ends  / search  Esc close".to_string(),
+            ModalState::Split(_) => {
+                "h/v direction  Up/Down size  Enter split  Esc cancel".to_string()
+            }
             ModalState::None => match app.focus {
                 FocusArea::Sessions => {
                     "Tab focus  j/k move  n new  R rename  d delete  Enter attach  / filter  ? help"
@@ -942,7 +1877,7 @@ fn centered_rect(percent_x: u16, percent_y: u16, area: Rect) -> Rect {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::app::{App, ConfirmModalState, FilterState, HelpOverlayState, InputModalState};
+    use crate::app::{App, FilterState, HelpOverlayState, InputModalState};
     use crate::models::{Pane, Session, Window};
     use ratatui::backend::TestBackend;
 
@@ -954,12 +1889,20 @@ mod tests {
                     name: "development".to_string(),
                     window_count: 2,
                     created: "Sun Apr 19 12:00:00 2026".to_string(),
+                    created_at: 1_776_600_000,
+                    last_activity: 1_776_600_000,
+                    attached: 1,
+                    destroy_unattached: false,
                 },
                 Session {
                     id: "%1".to_string(),
                     name: "operations".to_string(),
                     window_count: 1,
                     created: "Sun Apr 19 13:00:00 2026".to_string(),
+                    created_at: 1_776_603_600,
+                    last_activity: 1_776_603_600,
+                    attached: 0,
+                    destroy_unattached: false,
                 },
             ],
             windows: vec![
@@ -968,21 +1911,32 @@ mod tests {
                     name: "editor".to_string(),
                     active: true,
                     layout: "main-vertical".to_string(),
+                    path: "/tmp/project".to_string(),
+                    aggressive_resize: false,
+                    synchronized: false,
                 },
                 Window {
                     id: "@2".to_string(),
                     name: "logs".to_string(),
                     active: false,
                     layout: "tiled".to_string(),
+                    path: "/var/log".to_string(),
+                    aggressive_resize: false,
+                    synchronized: false,
                 },
             ],
             panes: vec![Pane {
                 id: "%11".to_string(),
+                index: 0,
                 width: 120,
                 height: 30,
                 current_path: "/tmp/very/long/path/for/the/project/src".to_string(),
                 current_command: "cargo watch".to_string(),
                 active: true,
+                zoomed: false,
+                history_size: 5000,
+                history_limit: 50000,
+                title: String::new(),
             }],
             ..App::default()
         };
@@ -1045,7 +1999,59 @@ mod tests {
 
         assert!(output.contains("Wide"));
         assert!(output.contains("Selection"));
-        assert!(output.contains("cargo watch"));
+        assert!(output.contains("[0] cargo watch"));
+    }
+
+    #[test]
+    fn header_shows_the_configured_title() {
+        let mut app = sample_app();
+        app.header_title = "TMUXUI @ build-01".to_string();
+
+        let output = render_to_string(app, 140, 30);
+
+        assert!(output.contains(" TMUXUI @ build-01 "));
+    }
+
+    #[test]
+    fn header_shows_the_mouse_option() {
+        let mut app = sample_app();
+        app.mouse = Some(true);
+
+        let output = render_to_string(app, 100, 30);
+
+        assert!(output.contains("Mouse on"));
+    }
+
+    #[test]
+    fn marks_attached_sessions_with_a_dot() {
+        let app = sample_app();
+        assert_eq!(app.sessions[0].attached, 1);
+        assert_eq!(app.sessions[1].attached, 0);
+
+        let output = render_to_string(app, 100, 30);
+
+        assert!(output.contains("● development"));
+        assert!(!output.contains("● operations"));
+    }
+
+    #[test]
+    fn marks_sessions_that_destroy_when_unattached() {
+        let mut app = sample_app();
+        app.sessions[0].destroy_unattached = true;
+
+        let output = render_to_string(app, 100, 30);
+
+        assert!(output.contains("development  ephemeral"));
+    }
+
+    #[test]
+    fn header_names_the_selected_socket() {
+        let mut app = sample_app();
+        app.socket = Some("work".to_string());
+
+        let output = render_to_string(app, 100, 30);
+
+        assert!(output.contains("Socket work | 2 sessions"));
     }
 
     #[test]
@@ -1064,6 +2070,207 @@ mod tests {
         assert!(output.contains("create your first"));
     }
 
+    #[test]
+    fn names_the_custom_tmux_binary_when_it_is_missing() {
+        let mut app = sample_app();
+        app.sessions.clear();
+        app.windows.clear();
+        app.panes.clear();
+        app.connection = TmuxConnectionState::Missing;
+        app.tmux_binary = "/opt/tmux".to_string();
+
+        let output = render_to_string(app, 140, 30);
+
+        assert!(output.contains("`/opt/tmux` could not be run"));
+    }
+
+    #[test]
+    fn renders_pane_history_usage_in_details() {
+        let mut app = sample_app();
+        app.focus = FocusArea::Panes;
+
+        let output = render_to_string(app, 140, 40);
+
+        assert!(output.contains("History 5000/50000"));
+    }
+
+    #[test]
+    fn renders_window_path_in_details() {
+        let mut app = sample_app();
+        app.focus = FocusArea::Windows;
+
+        let output = render_to_string(app, 140, 40);
+
+        assert!(output.contains("Path    /tmp/project"));
+    }
+
+    #[test]
+    fn marks_zoomed_panes() {
+        let mut app = sample_app();
+        app.panes[0].zoomed = true;
+
+        let output = render_to_string(app, 140, 40);
+
+        assert!(output.contains("cargo watch  [Z]"));
+    }
+
+    #[test]
+    fn compact_mode_shows_only_commands_for_inactive_panes() {
+        let mut app = sample_app();
+        app.panes.push(Pane {
+            id: "%12".to_string(),
+            index: 1,
+            width: 80,
+            height: 20,
+            current_path: "/srv/hidden/path".to_string(),
+            current_command: "htop".to_string(),
+            active: false,
+            zoomed: false,
+            history_size: 0,
+            history_limit: 2000,
+            title: String::new(),
+        });
+        app.compact_inactive_panes = true;
+
+        let output = render_to_string(app, 140, 40);
+
+        assert!(output.contains("Panes [2] compact"));
+        assert!(output.contains("htop"));
+        assert!(!output.contains("/srv/hidden/path"));
+        assert!(output.contains("%11  120x30"));
+    }
+
+    #[test]
+    fn renders_only_the_visible_page_of_a_large_session_list() {
+        let mut app = sample_app();
+        app.sessions = (0..1000)
+            .map(|index| Session {
+                id: format!("${index}"),
+                name: format!("ci-{index:04}"),
+                window_count: 1,
+                created: "Sun Apr 19 12:00:00 2026".to_string(),
+                created_at: 1_776_600_000,
+                last_activity: 1_776_600_000,
+                attached: 0,
+                destroy_unattached: false,
+            })
+            .collect();
+        app.session_list_state.select(Some(998));
+
+        let output = render_to_string(app, 140, 40);
+
+        assert!(output.contains("Sessions [1000]"));
+        assert!(output.contains("ci-0998"));
+        assert!(!output.contains("ci-0000"));
+    }
+
+    #[test]
+    fn page_start_keeps_the_selection_in_view() {
+        assert_eq!(page_start(0, Some(5), 10, 1000), 0);
+        assert_eq!(page_start(0, Some(25), 10, 1000), 16);
+        assert_eq!(page_start(20, Some(12), 10, 1000), 12);
+        assert_eq!(page_start(995, Some(999), 10, 1000), 990);
+        assert_eq!(page_start(0, None, 10, 3), 0);
+    }
+
+    #[test]
+    fn renders_session_state_filter_in_the_title() {
+        let mut app = sample_app();
+        app.session_state_filter = SessionStateFilter::Detached;
+
+        let output = render_to_string(app, 140, 40);
+
+        assert!(output.contains("Sessions [1] detached"));
+        assert!(output.contains("operations"));
+        assert!(!output.contains("development"));
+    }
+
+    #[test]
+    fn lists_scroll_to_keep_the_selection_visible() {
+        let mut app = sample_app();
+        let (session, window, pane) = (
+            app.sessions[0].clone(),
+            app.windows[0].clone(),
+            app.panes[0].clone(),
+        );
+        app.sessions = (0..30)
+            .map(|index| Session {
+                id: format!("${index}"),
+                name: format!("session-{index}"),
+                ..session.clone()
+            })
+            .collect();
+        app.windows = (0..30)
+            .map(|index| Window {
+                id: format!("@{index}"),
+                name: format!("window-{index}"),
+                ..window.clone()
+            })
+            .collect();
+        app.panes = (0..30)
+            .map(|index| Pane {
+                id: format!("%{index}"),
+                current_command: format!("command-{index}"),
+                ..pane.clone()
+            })
+            .collect();
+        app.session_list_state.select(Some(29));
+        app.window_list_state.select(Some(29));
+        app.pane_list_state.select(Some(29));
+
+        let output = render_to_string(app, 160, 24);
+
+        assert!(output.contains("session-29"));
+        assert!(output.contains("window-29"));
+        assert!(output.contains("command-29"));
+        assert!(!output.contains("session-0 "));
+    }
+
+    #[test]
+    fn renders_pane_titles_beside_the_command() {
+        let mut app = sample_app();
+        app.panes[0].title = "api server".to_string();
+
+        let output = render_to_string(app, 140, 40);
+
+        assert!(output.contains("cargo watch  \"api server\""));
+    }
+
+    #[test]
+    fn renders_marked_panes() {
+        let mut app = sample_app();
+        app.marked_panes.push(crate::app::MarkedPane {
+            pane_id: "%11".to_string(),
+            window_id: "@1".to_string(),
+        });
+
+        let output = render_to_string(app, 140, 40);
+
+        assert!(output.contains("cargo watch  marked"));
+    }
+
+    #[test]
+    fn renders_watched_panes() {
+        let mut app = sample_app();
+        app.toggle_pane_watch("%11", "cargo watch");
+
+        let output = render_to_string(app, 140, 40);
+
+        assert!(output.contains("cargo watch  watching"));
+    }
+
+    #[test]
+    fn renders_ids_ahead_of_names_when_toggled() {
+        let mut app = sample_app();
+        app.show_ids = true;
+
+        let output = render_to_string(app, 140, 40);
+
+        assert!(output.contains("%0  development"));
+        assert!(output.contains("* @1  editor"));
+        assert!(output.contains("%11 cargo watch"));
+    }
+
     #[test]
     fn renders_filter_and_long_paths_without_four_line_panes() {
         let mut app = sample_app();
@@ -1080,13 +2287,23 @@ mod tests {
         assert!(output.contains("Path"));
     }
 
+    #[test]
+    fn input_view_scrolls_to_keep_the_cursor_visible() {
+        assert_eq!(input_view("work", 4, 10), ("work".to_string(), 4));
+        assert_eq!(input_view("work", 1, 10), ("work".to_string(), 1));
+        assert_eq!(input_view("abcdefghij", 10, 5), ("ghij".to_string(), 4));
+        assert_eq!(input_view("abcdefghij", 2, 5), ("abcde".to_string(), 2));
+    }
+
     #[test]
     fn renders_input_modal_with_inline_error() {
         let mut app = sample_app();
         app.modal = ModalState::Input(InputModalState {
             intent: InputIntent::NewSession,
             value: String::new(),
+            cursor: 0,
             error: Some("Enter a name to continue.".to_string()),
+            warning: None,
         });
 
         let output = render_to_string(app, 100, 30);
@@ -1096,17 +2313,305 @@ mod tests {
     }
 
     #[test]
-    fn renders_delete_confirmation() {
+    fn renders_attach_command_modal_for_the_selected_window() {
+        let mut app = sample_app();
+        app.focus = FocusArea::Windows;
+        app.open_input_modal(InputIntent::AttachCommand, "git status");
+
+        let output = render_to_string(app, 100, 30);
+
+        assert!(output.contains("Attach and Run"));
+        assert!(output.contains("Attach to editor"));
+        assert!(output.contains("Enter attach"));
+    }
+
+    #[test]
+    fn renders_pipe_command_modal() {
         let mut app = sample_app();
-        app.modal = ModalState::Confirm(ConfirmModalState {
-            intent: ConfirmIntent::Session,
+        app.focus = FocusArea::Panes;
+        app.open_input_modal(InputIntent::PipeCommand, "grep error");
+
+        let output = render_to_string(app, 120, 30);
+
+        assert!(output.contains("Pipe Scrollback"));
+        assert!(output.contains("Press Enter to run `grep error`."));
+        assert!(output.contains("Enter run"));
+    }
+
+    #[test]
+    fn send_keys_footer_shows_send_and_cancel() {
+        let mut app = sample_app();
+        app.focus = FocusArea::Panes;
+        app.open_input_modal(InputIntent::SendKeys, "make test");
+
+        let output = render_to_string(app, 100, 30);
+
+        assert!(output.contains("Send Keys"));
+        assert!(output.contains("Enter send  Esc cancel"));
+    }
+
+    #[test]
+    fn preview_panel_shows_the_last_captured_lines() {
+        let mut app = sample_app();
+        app.preview = Some(PanePreview {
+            pane_id: Some("%11".to_string()),
+            lines: (1..=40).map(|line| format!("build step {line}")).collect(),
             error: None,
         });
 
+        let output = render_to_string(app, 140, 30);
+
+        assert!(output.contains("Preview %11"));
+        assert!(output.contains("build step 40"));
+        assert!(!output.contains("build step 1 "));
+        assert!(!output.contains("Name"));
+    }
+
+    #[test]
+    fn marks_synchronized_windows() {
+        let mut app = sample_app();
+        app.windows[1].synchronized = true;
+
+        let output = render_to_string(app, 140, 30);
+
+        assert!(output.contains("@2  [SYNC]"));
+        assert!(!output.contains("@1  [SYNC]"));
+    }
+
+    #[test]
+    fn marks_windows_with_aggressive_resize() {
+        let mut app = sample_app();
+        app.windows[0].aggressive_resize = true;
+
+        let output = render_to_string(app, 140, 30);
+
+        assert!(output.contains("@1  aggressive"));
+    }
+
+    #[test]
+    fn renders_move_window_modal() {
+        let mut app = sample_app();
+        app.focus = FocusArea::Windows;
+        app.open_input_modal(InputIntent::MoveWindow, "ops");
+
+        let output = render_to_string(app, 100, 30);
+
+        assert!(output.contains("Move Window"));
+        assert!(output.contains("Move editor out of development"));
+        assert!(output.contains("move the window to `ops`"));
+    }
+
+    #[test]
+    fn renders_session_hook_modal_with_the_chosen_hook() {
+        let mut app = sample_app();
+        app.open_input_modal(InputIntent::SessionHook("after-new-window"), "display hi");
+
+        let output = render_to_string(app, 100, 30);
+
+        assert!(output.contains("Session Hook"));
+        assert!(output.contains("Run a tmux command in development on `after-new-window`"));
+        assert!(output.contains("Type a tmux command"));
+    }
+
+    #[test]
+    fn renders_delete_confirmation() {
+        let mut app = sample_app();
+        app.open_confirm_modal(ConfirmIntent::Session);
+
         let output = render_to_string(app, 100, 30);
 
         assert!(output.contains("Delete Session"));
         assert!(output.contains("This closes all 2 window"));
+        assert!(output.contains("Windows (2)"));
+        assert!(output.contains("  logs"));
+    }
+
+    #[test]
+    fn delete_confirmation_warns_about_the_last_session_in_safe_mode() {
+        let mut app = sample_app();
+        app.sessions.truncate(1);
+        app.open_confirm_modal(ConfirmIntent::Session);
+
+        let output = render_to_string(app, 100, 30);
+
+        assert!(output.contains("Safe mode"));
+        assert!(output.contains("safe_mode = off"));
+    }
+
+    #[test]
+    fn new_session_asks_for_a_start_directory() {
+        let mut app = sample_app();
+        app.pending_session = Some("api".to_string());
+        app.open_input_modal(InputIntent::SessionDirectory, "~/src/api");
+
+        let output = render_to_string(app, 110, 30);
+
+        assert!(output.contains("Choose the directory `api` starts in"));
+        assert!(output.contains("~/src/api"));
+        assert!(output.contains("Type a directory"));
+    }
+
+    #[test]
+    fn new_window_prompt_names_the_inherited_directory() {
+        let mut app = sample_app();
+        app.open_input_modal(InputIntent::NewWindow, "");
+
+        let output = render_to_string(app, 140, 30);
+
+        assert!(output.contains("starting in /tmp/very/long/path/for/the/project/src"));
+    }
+
+    #[test]
+    fn grouped_session_prompt_explains_shared_windows() {
+        let mut app = sample_app();
+        app.open_input_modal(InputIntent::GroupedSession, "development-2");
+
+        let output = render_to_string(app, 120, 30);
+
+        assert!(output.contains("Grouped Session"));
+        assert!(output.contains("share the same windows"));
+    }
+
+    #[test]
+    fn kill_server_asks_to_type_yes() {
+        let mut app = sample_app();
+        app.open_input_modal(InputIntent::KillServer, "");
+
+        let output = render_to_string(app, 100, 30);
+
+        assert!(output.contains("Stop tmux Server"));
+        assert!(output.contains("its 2 session(s)"));
+        assert!(output.contains("Type yes"));
+    }
+
+    #[test]
+    fn renders_pane_position_picker() {
+        let mut app = sample_app();
+        app.focus = FocusArea::Panes;
+        let items = app.pane_position_items();
+        app.open_picker_modal(PickerIntent::PanePosition, items, Some(0));
+
+        let output = render_to_string(app, 100, 30);
+
+        assert!(output.contains("Move Pane"));
+        assert!(output.contains("0  top-left"));
+        assert!(output.contains("Enter apply"));
+    }
+
+    #[test]
+    fn renders_buffers_picker() {
+        let mut app = sample_app();
+        app.buffers = vec![crate::models::Buffer {
+            name: "tmuxui-11-1776600000".to_string(),
+            size: 42,
+            sample: "cargo build\\n".to_string(),
+        }];
+        let items = app.buffer_items();
+        app.open_picker_modal(PickerIntent::Buffers, items, Some(0));
+
+        let output = render_to_string(app, 100, 30);
+
+        assert!(output.contains("Paste Buffers"));
+        assert!(output.contains("tmuxui-11-1776600000"));
+        assert!(output.contains("42 bytes"));
+        assert!(output.contains("d delete"));
+    }
+
+    #[test]
+    fn renders_saved_layouts_picker() {
+        let mut app = sample_app();
+        app.focus = FocusArea::Windows;
+        app.layout_presets = vec![crate::config::LayoutPreset {
+            name: "editor + logs".to_string(),
+            layout: "b1e2,100x40,0,0{50x40,0,0,0,49x40,51,0,1}".to_string(),
+        }];
+        let items = app.layout_preset_items();
+        app.open_picker_modal(PickerIntent::LayoutPresets, items, Some(0));
+
+        let output = render_to_string(app, 100, 30);
+
+        assert!(output.contains("Saved Layouts"));
+        assert!(output.contains("editor + logs"));
+        assert!(output.contains("d delete"));
+    }
+
+    #[test]
+    fn renders_split_preview() {
+        let mut app = sample_app();
+        let pane = app.panes[0].clone();
+        app.open_split_modal(&pane);
+        if let Some(modal) = app.split_modal_mut() {
+            modal.shrink();
+        }
+
+        let output = render_to_string(app, 100, 30);
+
+        assert!(output.contains("Split Pane"));
+        assert!(output.contains("new 45%"));
+        assert!(output.contains("h/v direction"));
+        assert!(output.contains("Press h to split"));
+    }
+
+    #[test]
+    fn renders_action_menu() {
+        let mut app = sample_app();
+        app.focus = FocusArea::Windows;
+        let items = app.menu_action_items();
+        app.open_picker_modal(PickerIntent::Actions, items, Some(0));
+
+        let output = render_to_string(app, 100, 30);
+
+        assert!(output.contains("Actions"));
+        assert!(output.contains("selected window"));
+        assert!(output.contains("Clone window"));
+        assert!(output.contains("Enter run"));
+    }
+
+    #[test]
+    fn renders_scrollback_pager_with_search() {
+        let mut app = sample_app();
+        let mut pager =
+            crate::app::PagerState::new("Scrollback %11", "build ok\nerror: boom\ndone");
+        for character in "error".chars() {
+            pager.push_query_char(character);
+        }
+        app.open_pager(pager);
+
+        let output = render_to_string(app, 100, 30);
+
+        assert!(output.contains("Scrollback %11"));
+        assert!(output.contains("error: boom"));
+        assert!(output.contains("1 of 3 lines"));
+        assert!(!output.contains("build ok"));
+    }
+
+    #[test]
+    fn renders_pane_list_beside_scrollback_when_it_has_focus() {
+        let mut app = sample_app();
+        let mut pager = crate::app::PagerState::new("Scrollback %11", "build ok");
+        pager.pane_id = Some("%11".to_string());
+        pager.toggle_focus();
+        app.open_pager(pager);
+
+        let output = render_to_string(app, 100, 30);
+
+        assert!(output.contains("[0] cargo watch"));
+        assert!(output.contains("build ok"));
+        assert!(output.contains("Tab scrollback"));
+    }
+
+    #[test]
+    fn renders_colored_scrollback_as_styled_text() {
+        let mut app = sample_app();
+        let mut pager = crate::app::PagerState::new("Scrollback %11", "");
+        pager.ansi = true;
+        pager.show_pane("%11", "\u{1b}[31merror\u{1b}[0m: boom");
+        app.open_pager(pager);
+
+        let output = render_to_string(app, 100, 30);
+
+        assert!(output.contains("error: boom"));
+        assert!(!output.contains("[31m"));
     }
 
     #[test]
@@ -0,0 +1,158 @@
+use crate::config::TimeDisplay;
+use anyhow::{bail, Context, Result};
+use chrono::{DateTime, Local, Utc};
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    process::Command,
+};
+
+const CTIME_FORMAT: &str = "%a %b %e %H:%M:%S %Y";
+
+pub fn format_timestamp(epoch: i64, display: TimeDisplay) -> Option<String> {
+    let utc = DateTime::<Utc>::from_timestamp(epoch, 0)?;
+    match display {
+        TimeDisplay::Server => None,
+        TimeDisplay::Local => Some(utc.with_timezone(&Local).format(CTIME_FORMAT).to_string()),
+        TimeDisplay::Utc => Some(utc.format(CTIME_FORMAT).to_string()),
+    }
+}
+
+pub fn git_branch(path: &str) -> Result<String> {
+    let output = Command::new("git")
+        .args(["-C", path, "rev-parse", "--abbrev-ref", "HEAD"])
+        .output()
+        .context("could not run git")?;
+
+    if !output.status.success() {
+        bail!("`{path}` is not inside a git repository");
+    }
+
+    match parse_branch(&String::from_utf8_lossy(&output.stdout)) {
+        Some(branch) => Ok(branch),
+        None => bail!("`{path}` has no branch checked out"),
+    }
+}
+
+pub fn hostname() -> Option<String> {
+    let output = Command::new("hostname").output().ok()?;
+    let name = String::from_utf8_lossy(&output.stdout).trim().to_string();
+    (output.status.success() && !name.is_empty()).then_some(name)
+}
+
+pub fn header_title(template: Option<&str>, hostname: Option<&str>) -> String {
+    let host = hostname.unwrap_or("localhost");
+    match template {
+        Some(template) => template.replace("{host}", host),
+        None => match hostname {
+            Some(host) => format!("TMUXUI @ {host}"),
+            None => "TMUXUI".to_string(),
+        },
+    }
+}
+
+pub fn expand_home(path: &str, home: Option<&str>) -> PathBuf {
+    match (path.strip_prefix('~'), home) {
+        (Some(rest), Some(home)) if rest.is_empty() || rest.starts_with('/') => {
+            PathBuf::from(format!("{home}{rest}"))
+        }
+        _ => PathBuf::from(path),
+    }
+}
+
+pub fn subdirectories(parent: &Path) -> Result<Vec<(String, PathBuf)>> {
+    let entries =
+        fs::read_dir(parent).with_context(|| format!("could not read `{}`", parent.display()))?;
+
+    let mut dirs = Vec::new();
+    for entry in entries {
+        let entry = entry.with_context(|| format!("could not read `{}`", parent.display()))?;
+        let name = entry.file_name().to_string_lossy().into_owned();
+        if name.starts_with('.') || !entry.path().is_dir() {
+            continue;
+        }
+        dirs.push((name, entry.path()));
+    }
+    dirs.sort();
+    Ok(dirs)
+}
+
+fn parse_branch(raw: &str) -> Option<String> {
+    let branch = raw.trim();
+    (!branch.is_empty() && branch != "HEAD").then(|| branch.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn header_title_defaults_to_the_hostname() {
+        assert_eq!(header_title(None, Some("build-01")), "TMUXUI @ build-01");
+        assert_eq!(header_title(None, None), "TMUXUI");
+        assert_eq!(
+            header_title(Some("prod ({host})"), Some("db-2")),
+            "prod (db-2)"
+        );
+        assert_eq!(header_title(Some("Staging"), Some("db-2")), "Staging");
+    }
+
+    #[test]
+    fn expands_a_leading_tilde_to_home() {
+        assert_eq!(
+            expand_home("~/src", Some("/home/ana")),
+            PathBuf::from("/home/ana/src")
+        );
+        assert_eq!(
+            expand_home("~", Some("/home/ana")),
+            PathBuf::from("/home/ana")
+        );
+        assert_eq!(
+            expand_home("~bob/src", Some("/home/ana")),
+            PathBuf::from("~bob/src")
+        );
+        assert_eq!(
+            expand_home("/srv", Some("/home/ana")),
+            PathBuf::from("/srv")
+        );
+        assert_eq!(expand_home("~/src", None), PathBuf::from("~/src"));
+    }
+
+    #[test]
+    fn lists_visible_subdirectories_in_name_order() {
+        let parent = std::env::temp_dir().join(format!("tmuxui-subdirs-{}", std::process::id()));
+        for dir in ["web", "api", ".git"] {
+            fs::create_dir_all(parent.join(dir)).unwrap();
+        }
+        fs::write(parent.join("README.md"), "").unwrap();
+
+        let names: Vec<String> = subdirectories(&parent)
+            .unwrap()
+            .into_iter()
+            .map(|(name, _)| name)
+            .collect();
+        fs::remove_dir_all(&parent).unwrap();
+
+        assert_eq!(names, ["api", "web"]);
+        assert!(subdirectories(&parent).is_err());
+    }
+
+    #[test]
+    fn formats_utc_timestamps_like_tmux() {
+        assert_eq!(
+            format_timestamp(1_776_600_000, TimeDisplay::Utc).as_deref(),
+            Some("Sun Apr 19 12:00:00 2026")
+        );
+        assert_eq!(format_timestamp(1_776_600_000, TimeDisplay::Server), None);
+    }
+
+    #[test]
+    fn parses_branch_names_and_skips_detached_heads() {
+        assert_eq!(
+            parse_branch("feature/login\n").as_deref(),
+            Some("feature/login")
+        );
+        assert_eq!(parse_branch("HEAD\n"), None);
+        assert_eq!(parse_branch(""), None);
+    }
+}